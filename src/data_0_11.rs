@@ -1,27 +1,70 @@
 use crate::generate::{
-    store_versioned_auxiliary_tfhe_0_11, store_versioned_test_tfhe_0_11, TfhersVersion, PRNG_SEED,
-    VALID_TEST_PARAMS_TUNIFORM,
+    store_versioned_auxiliary_tfhe_0_11, store_versioned_test_tfhe_0_11, test_seed,
+    ModuleGenerator, TfhersVersion, INSECURE_SMALL_BOOLEAN_TEST_PARAMS,
+    INSECURE_SMALL_PK_TEST_PARAMS, PRNG_SEED, VALID_TEST_PARAMS_TUNIFORM,
 };
 use crate::{
-    DataKind, HlClientKeyTest, HlHeterogeneousCiphertextListTest, PkeZkProofAuxiliaryInfo,
-    TestDistribution, TestMetadata, TestParameterSet, ZkPkePublicParamsTest, HL_MODULE_NAME,
+    container_digest, BooleanCiphertextTest, BooleanClientKeyTest, BooleanPublicKeyTest,
+    BooleanServerKeyTest, CoreCryptoGgswCiphertextTest, CoreCryptoGlweCiphertextTest,
+    CoreCryptoGlweSecretKeyTest, CoreCryptoLweBootstrapKeyTest, CoreCryptoLweCiphertextListTest,
+    CoreCryptoLweCiphertextTest, CoreCryptoLweCompactCiphertextListTest,
+    CoreCryptoLweCompactPublicKeyTest, CoreCryptoLweKeyswitchKeyTest,
+    CoreCryptoLweMultiBitBootstrapKeyTest, CoreCryptoLwePackingKeyswitchKeyTest,
+    CoreCryptoLweSecretKeyTest, CoreCryptoSeededEntity, CoreCryptoSeededEntityTest, DataKind,
+    HlClientKeyTest, HlHeterogeneousCiphertextListTest, HlSignedWideCiphertextTest,
+    HlWideCiphertextTest, IntegerBooleanBlockTest, IntegerClientKeyTest, IntegerCrtCiphertextTest,
+    IntegerRadixCiphertextTest, IntegerSignedRadixCiphertextTest, PkeZkProofAuxiliaryInfo,
+    ShortintCiphertextTest, ShortintClientKeyTest, ShortintCompactCiphertextListTest,
+    ShortintCompressedCiphertextTest, ShortintKeySwitchingKeyTest, ShortintPublicKeyTest,
+    ShortintServerKeyTest, TestBooleanParameterSet, TestDistribution, TestMetadata,
+    TestParameterSet, TestWideInteger, ZkPkeProofTest, ZkPkePublicParamsTest, BOOLEAN_MODULE_NAME,
+    CORE_CRYPTO_MODULE_NAME, HL_MODULE_NAME, INTEGER_MODULE_NAME, SHORTINT_MODULE_NAME,
+    ZK_MODULE_NAME,
 };
 use std::borrow::Cow;
 use std::fs::create_dir_all;
+use std::path::Path;
+use tfhe_0_11::boolean;
 use tfhe_0_11::boolean::engine::BooleanEngine;
+use tfhe_0_11::boolean::parameters::BooleanParameters;
 use tfhe_0_11::core_crypto::commons::generators::DeterministicSeeder;
 use tfhe_0_11::core_crypto::commons::math::random::{DefaultRandomGenerator, RandomGenerator};
-use tfhe_0_11::core_crypto::prelude::LweCiphertextCount;
 use tfhe_0_11::core_crypto::prelude::TUniform;
+use tfhe_0_11::core_crypto::prelude::{
+    allocate_and_encrypt_new_lwe_ciphertext, allocate_and_encrypt_new_seeded_lwe_ciphertext,
+    allocate_and_generate_new_binary_glwe_secret_key,
+    allocate_and_generate_new_binary_lwe_secret_key, allocate_and_generate_new_lwe_bootstrap_key,
+    allocate_and_generate_new_lwe_compact_public_key, allocate_and_generate_new_lwe_keyswitch_key,
+    allocate_and_generate_new_lwe_multi_bit_bootstrap_key,
+    allocate_and_generate_new_lwe_packing_keyswitch_key,
+    allocate_and_generate_new_seeded_lwe_bootstrap_key,
+    allocate_and_generate_new_seeded_lwe_compact_public_key,
+    allocate_and_generate_new_seeded_lwe_keyswitch_key,
+    convert_standard_lwe_bootstrap_key_to_fourier,
+    encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key,
+    encrypt_constant_ggsw_ciphertext, encrypt_glwe_ciphertext, encrypt_lwe_ciphertext_list,
+    encrypt_lwe_compact_ciphertext_list_with_compact_public_key, encrypt_seeded_glwe_ciphertext,
+    Cleartext, EncryptionRandomGenerator, FourierLweBootstrapKey, GgswCiphertext, GlweCiphertext,
+    LweBskGroupingFactor, LweCiphertextCount, LweCiphertextList, LweCompactCiphertextList,
+    Plaintext, PlaintextList, SecretRandomGenerator, SeededGlweCiphertext, Seeder, UnsignedInteger,
+};
+use tfhe_0_11::integer;
+use tfhe_0_11::integer::{I256, U256};
+use tfhe_0_11::prelude::*;
+use tfhe_0_11::shortint;
 use tfhe_0_11::shortint::engine::ShortintEngine;
 use tfhe_0_11::shortint::parameters::{
     CarryModulus, CiphertextModulus, ClassicPBSParameters, DecompositionBaseLog,
     DecompositionLevelCount, DynamicDistribution, EncryptionKeyChoice, GlweDimension, LweDimension,
-    MaxNoiseLevel, MessageModulus, PBSParameters, PolynomialSize, StandardDev,
+    MaxNoiseLevel, MessageModulus, PBSParameters, PolynomialSize, ShortintKeySwitchingParameters,
+    StandardDev,
 };
 use tfhe_0_11::zk::{CompactPkeCrs, ZkComputeLoad, ZkMSBZeroPaddingBitCount};
 use tfhe_0_11::{set_server_key, CompactPublicKey, ProvenCompactCiphertextList, ServerKey};
-use tfhe_0_11::{ClientKey, Seed};
+use tfhe_0_11::{
+    ClientKey, FheInt128, FheInt16, FheInt160, FheInt256, FheInt64, FheUint128, FheUint16,
+    FheUint160, FheUint256, FheUint32, FheUint4, FheUint64, Seed,
+};
 
 macro_rules! store_versioned_test {
     ($msg:expr, $dir:expr, $test_filename:expr $(,)? ) => {
@@ -35,7 +78,7 @@ macro_rules! store_versioned_auxiliary {
     };
 }
 
-impl From<TestDistribution> for DynamicDistribution<u64> {
+impl<Scalar: UnsignedInteger> From<TestDistribution> for DynamicDistribution<Scalar> {
     fn from(value: TestDistribution) -> Self {
         match value {
             TestDistribution::Gaussian { stddev } => {
@@ -48,6 +91,29 @@ impl From<TestDistribution> for DynamicDistribution<u64> {
     }
 }
 
+impl From<TestBooleanParameterSet> for BooleanParameters {
+    fn from(value: TestBooleanParameterSet) -> Self {
+        BooleanParameters {
+            lwe_dimension: LweDimension(value.lwe_dimension),
+            glwe_dimension: GlweDimension(value.glwe_dimension),
+            polynomial_size: PolynomialSize(value.polynomial_size),
+            lwe_noise_distribution: value.lwe_noise_distribution.into(),
+            glwe_noise_distribution: value.glwe_noise_distribution.into(),
+            pbs_base_log: DecompositionBaseLog(value.pbs_base_log),
+            pbs_level: DecompositionLevelCount(value.pbs_level),
+            ks_base_log: DecompositionBaseLog(value.ks_base_log),
+            ks_level: DecompositionLevelCount(value.ks_level),
+            encryption_key_choice: {
+                match &*value.encryption_key_choice {
+                    "big" => EncryptionKeyChoice::Big,
+                    "small" => EncryptionKeyChoice::Small,
+                    _ => panic!("Invalid encryption key choice"),
+                }
+            },
+        }
+    }
+}
+
 impl From<TestParameterSet> for ClassicPBSParameters {
     fn from(value: TestParameterSet) -> Self {
         ClassicPBSParameters {
@@ -76,6 +142,22 @@ impl From<TestParameterSet> for ClassicPBSParameters {
     }
 }
 
+impl From<&ShortintKeySwitchingKeyTest> for ShortintKeySwitchingParameters {
+    fn from(value: &ShortintKeySwitchingKeyTest) -> Self {
+        ShortintKeySwitchingParameters {
+            ks_base_log: DecompositionBaseLog(value.ks_base_log),
+            ks_level: DecompositionLevelCount(value.ks_level),
+            destination_key: {
+                match &*value.destination_key {
+                    "big" => EncryptionKeyChoice::Big,
+                    "small" => EncryptionKeyChoice::Small,
+                    _ => panic!("Invalid encryption key choice"),
+                }
+            },
+        }
+    }
+}
+
 impl From<TestParameterSet> for PBSParameters {
     fn from(value: TestParameterSet) -> Self {
         let tmp: ClassicPBSParameters = value.into();
@@ -124,10 +206,528 @@ const HL_PROVEN_COMPACTLIST_TEST_ZKV2: HlHeterogeneousCiphertextListTest =
         }),
     };
 
+const ZK_PKE_PROOF_ZKV2_TEST: ZkPkeProofTest = ZkPkeProofTest {
+    test_filename: Cow::Borrowed("pke_proof_zkv2"),
+    params_filename: ZK_PKE_CRS_TEST.test_filename,
+    public_key_filename: Cow::Borrowed("pke_proof_zkv2_public_key"),
+    key_filename: Cow::Borrowed("pke_proof_zkv2_secret_key"),
+    ciphertext_list_filename: Cow::Borrowed("pke_proof_zkv2_ciphertext_list"),
+    metadata: Cow::Borrowed("2vdrawkcab"),
+    clear_values: Cow::Borrowed(&[3, 0, 12, 7]),
+    delta: (1u64 << 63) / (ZK_PKE_CRS_TEST.plaintext_modulus as u64 / 2), // /2 for padding bit
+};
+
+const HL_WIDE_INTEGERS_CLIENT_KEY_FILENAME: &str = "client_key_wide_integers";
+
+const HL_UINT4_CT_TEST: HlWideCiphertextTest = HlWideCiphertextTest {
+    test_filename: Cow::Borrowed("ct_uint4"),
+    key_filename: Cow::Borrowed(HL_WIDE_INTEGERS_CLIENT_KEY_FILENAME),
+    compressed: false,
+    num_bits: 4,
+    clear_value: TestWideInteger::from_u64(13),
+};
+
+const HL_UINT16_CT_TEST: HlWideCiphertextTest = HlWideCiphertextTest {
+    test_filename: Cow::Borrowed("ct_uint16"),
+    key_filename: Cow::Borrowed(HL_WIDE_INTEGERS_CLIENT_KEY_FILENAME),
+    compressed: false,
+    num_bits: 16,
+    clear_value: TestWideInteger::from_u64(48879),
+};
+
+const HL_UINT32_CT_TEST: HlWideCiphertextTest = HlWideCiphertextTest {
+    test_filename: Cow::Borrowed("ct_uint32"),
+    key_filename: Cow::Borrowed(HL_WIDE_INTEGERS_CLIENT_KEY_FILENAME),
+    compressed: false,
+    num_bits: 32,
+    clear_value: TestWideInteger::from_u64(3141592653),
+};
+
+const HL_UINT64_CT_TEST: HlWideCiphertextTest = HlWideCiphertextTest {
+    test_filename: Cow::Borrowed("ct_uint64"),
+    key_filename: Cow::Borrowed(HL_WIDE_INTEGERS_CLIENT_KEY_FILENAME),
+    compressed: false,
+    num_bits: 64,
+    clear_value: TestWideInteger::from_u64(u64::MAX - 1),
+};
+
+const HL_UINT128_CT_TEST: HlWideCiphertextTest = HlWideCiphertextTest {
+    test_filename: Cow::Borrowed("ct_uint128"),
+    key_filename: Cow::Borrowed(HL_WIDE_INTEGERS_CLIENT_KEY_FILENAME),
+    compressed: false,
+    num_bits: 128,
+    clear_value: TestWideInteger::from_u128(u128::MAX / 3),
+};
+
+const HL_UINT160_CT_TEST: HlWideCiphertextTest = HlWideCiphertextTest {
+    test_filename: Cow::Borrowed("ct_uint160"),
+    key_filename: Cow::Borrowed(HL_WIDE_INTEGERS_CLIENT_KEY_FILENAME),
+    compressed: false,
+    num_bits: 160,
+    clear_value: TestWideInteger::from_words([
+        0x0123456789abcdef,
+        0xfedcba9876543210,
+        0xdeadbeef,
+        0,
+    ]),
+};
+
+const HL_UINT256_CT_TEST: HlWideCiphertextTest = HlWideCiphertextTest {
+    test_filename: Cow::Borrowed("ct_uint256"),
+    key_filename: Cow::Borrowed(HL_WIDE_INTEGERS_CLIENT_KEY_FILENAME),
+    compressed: false,
+    num_bits: 256,
+    clear_value: TestWideInteger::from_words([u64::MAX, 0, 0xa5a5a5a5a5a5a5a5, 1 << 63]),
+};
+
+const HL_INT16_CT_TEST: HlSignedWideCiphertextTest = HlSignedWideCiphertextTest {
+    test_filename: Cow::Borrowed("ct_int16"),
+    key_filename: Cow::Borrowed(HL_WIDE_INTEGERS_CLIENT_KEY_FILENAME),
+    compressed: false,
+    num_bits: 16,
+    clear_value: TestWideInteger::from_i64(-12345),
+};
+
+const HL_INT64_CT_TEST: HlSignedWideCiphertextTest = HlSignedWideCiphertextTest {
+    test_filename: Cow::Borrowed("ct_int64"),
+    key_filename: Cow::Borrowed(HL_WIDE_INTEGERS_CLIENT_KEY_FILENAME),
+    compressed: false,
+    num_bits: 64,
+    clear_value: TestWideInteger::from_i64(i64::MIN + 1),
+};
+
+const HL_INT128_CT_TEST: HlSignedWideCiphertextTest = HlSignedWideCiphertextTest {
+    test_filename: Cow::Borrowed("ct_int128"),
+    key_filename: Cow::Borrowed(HL_WIDE_INTEGERS_CLIENT_KEY_FILENAME),
+    compressed: false,
+    num_bits: 128,
+    clear_value: TestWideInteger::from_i128(i128::MIN / 3),
+};
+
+const HL_INT160_CT_TEST: HlSignedWideCiphertextTest = HlSignedWideCiphertextTest {
+    test_filename: Cow::Borrowed("ct_int160"),
+    key_filename: Cow::Borrowed(HL_WIDE_INTEGERS_CLIENT_KEY_FILENAME),
+    compressed: false,
+    num_bits: 160,
+    clear_value: TestWideInteger::from_words([1, 0, 0xffffffff80000000, u64::MAX]),
+};
+
+const HL_INT256_CT_TEST: HlSignedWideCiphertextTest = HlSignedWideCiphertextTest {
+    test_filename: Cow::Borrowed("ct_int256"),
+    key_filename: Cow::Borrowed(HL_WIDE_INTEGERS_CLIENT_KEY_FILENAME),
+    compressed: false,
+    num_bits: 256,
+    clear_value: TestWideInteger::from_words([0, 0, 0, 1 << 63]),
+};
+
+// Shortint
+const SHORTINT_CLIENT_KEY_FILENAME: &str = "client_key";
+
+// WARNING: these parameters are completely insecure, they are only used to keep the server keys
+// small
+const SHORTINT_CLIENTKEY_TEST: ShortintClientKeyTest = ShortintClientKeyTest {
+    test_filename: Cow::Borrowed(SHORTINT_CLIENT_KEY_FILENAME),
+    parameters: INSECURE_SMALL_PK_TEST_PARAMS,
+};
+
+const SHORTINT_SERVERKEY_TEST: ShortintServerKeyTest = ShortintServerKeyTest {
+    test_filename: Cow::Borrowed("server_key"),
+    client_key_filename: Cow::Borrowed(SHORTINT_CLIENT_KEY_FILENAME),
+    compressed: false,
+};
+
+const SHORTINT_COMPRESSED_SERVERKEY_TEST: ShortintServerKeyTest = ShortintServerKeyTest {
+    test_filename: Cow::Borrowed("compressed_server_key"),
+    client_key_filename: Cow::Borrowed(SHORTINT_CLIENT_KEY_FILENAME),
+    compressed: true,
+};
+
+// The seeded ciphertext is compressed with the client key
+const SHORTINT_COMPRESSED_CT_TEST: ShortintCompressedCiphertextTest =
+    ShortintCompressedCiphertextTest {
+        test_filename: Cow::Borrowed("ct_compressed_seeded"),
+        key_filename: Cow::Borrowed(SHORTINT_CLIENT_KEY_FILENAME),
+        modulus_switched: false,
+        clear_value: 1,
+    };
+
+// The modulus switched ciphertext is compressed with the server key
+const SHORTINT_COMPRESSED_CT_MODSWITCHED_TEST: ShortintCompressedCiphertextTest =
+    ShortintCompressedCiphertextTest {
+        test_filename: Cow::Borrowed("ct_compressed_modswitched"),
+        key_filename: Cow::Borrowed(SHORTINT_CLIENT_KEY_FILENAME),
+        modulus_switched: true,
+        clear_value: 0,
+    };
+
+// The ciphertexts use the TUniform parameters, which are too large for the server keys
+const SHORTINT_TUNIFORM_CLIENT_KEY_FILENAME: &str = "client_key_tuniform";
+
+const SHORTINT_TUNIFORM_CLIENTKEY_TEST: ShortintClientKeyTest = ShortintClientKeyTest {
+    test_filename: Cow::Borrowed(SHORTINT_TUNIFORM_CLIENT_KEY_FILENAME),
+    parameters: VALID_TEST_PARAMS_TUNIFORM,
+};
+
+const SHORTINT_CT1_TEST: ShortintCiphertextTest = ShortintCiphertextTest {
+    test_filename: Cow::Borrowed("ct1"),
+    key_filename: Cow::Borrowed(SHORTINT_TUNIFORM_CLIENT_KEY_FILENAME),
+    clear_value: 0,
+};
+
+const SHORTINT_CT2_TEST: ShortintCiphertextTest = ShortintCiphertextTest {
+    test_filename: Cow::Borrowed("ct2"),
+    key_filename: Cow::Borrowed(SHORTINT_TUNIFORM_CLIENT_KEY_FILENAME),
+    clear_value: 3,
+};
+
+// The legacy public keys are generated with the small parameters to keep them small
+const SHORTINT_PUBKEY_TEST: ShortintPublicKeyTest = ShortintPublicKeyTest {
+    test_filename: Cow::Borrowed("public_key"),
+    client_key_filename: Cow::Borrowed(SHORTINT_CLIENT_KEY_FILENAME),
+    compressed: false,
+    compact: false,
+    clear_value: 1,
+};
+
+const SHORTINT_COMPRESSED_PUBKEY_TEST: ShortintPublicKeyTest = ShortintPublicKeyTest {
+    test_filename: Cow::Borrowed("compressed_public_key"),
+    client_key_filename: Cow::Borrowed(SHORTINT_CLIENT_KEY_FILENAME),
+    compressed: true,
+    compact: false,
+    clear_value: 0,
+};
+
+// The compact public keys need a power of two encryption lwe dimension
+const SHORTINT_COMPACT_PUBKEY_TEST: ShortintPublicKeyTest = ShortintPublicKeyTest {
+    test_filename: Cow::Borrowed("compact_public_key"),
+    client_key_filename: Cow::Borrowed(SHORTINT_TUNIFORM_CLIENT_KEY_FILENAME),
+    compressed: false,
+    compact: true,
+    clear_value: 2,
+};
+
+const SHORTINT_COMPRESSED_COMPACT_PUBKEY_TEST: ShortintPublicKeyTest = ShortintPublicKeyTest {
+    test_filename: Cow::Borrowed("compressed_compact_public_key"),
+    client_key_filename: Cow::Borrowed(SHORTINT_TUNIFORM_CLIENT_KEY_FILENAME),
+    compressed: true,
+    compact: true,
+    clear_value: 3,
+};
+
+// The compact lists are encrypted with the compact public key
+const SHORTINT_COMPACT_LIST_TEST: ShortintCompactCiphertextListTest =
+    ShortintCompactCiphertextListTest {
+        test_filename: Cow::Borrowed("compact_list"),
+        key_filename: Cow::Borrowed(SHORTINT_TUNIFORM_CLIENT_KEY_FILENAME),
+        packed: false,
+        clear_values: Cow::Borrowed(&[0, 3, 1, 2]),
+    };
+
+const SHORTINT_PACKED_COMPACT_LIST_TEST: ShortintCompactCiphertextListTest =
+    ShortintCompactCiphertextListTest {
+        test_filename: Cow::Borrowed("packed_compact_list"),
+        key_filename: Cow::Borrowed(SHORTINT_TUNIFORM_CLIENT_KEY_FILENAME),
+        packed: true,
+        clear_values: Cow::Borrowed(&[7, 12, 15]),
+    };
+
+// The keyswitching keys cast from the client key to another one with the same parameters
+const SHORTINT_KSK_OUTPUT_CLIENT_KEY_FILENAME: &str = "client_key_ksk_output";
+
+const SHORTINT_KSK_OUTPUT_CLIENTKEY_TEST: ShortintClientKeyTest = ShortintClientKeyTest {
+    test_filename: Cow::Borrowed(SHORTINT_KSK_OUTPUT_CLIENT_KEY_FILENAME),
+    parameters: INSECURE_SMALL_PK_TEST_PARAMS,
+};
+
+const SHORTINT_KSK_TEST: ShortintKeySwitchingKeyTest = ShortintKeySwitchingKeyTest {
+    test_filename: Cow::Borrowed("keyswitching_key"),
+    input_key_filename: Cow::Borrowed(SHORTINT_CLIENT_KEY_FILENAME),
+    output_key_filename: Cow::Borrowed(SHORTINT_KSK_OUTPUT_CLIENT_KEY_FILENAME),
+    compressed: false,
+    ks_base_log: 5,
+    ks_level: 3,
+    destination_key: Cow::Borrowed("small"),
+    clear_value: 1,
+};
+
+const SHORTINT_COMPRESSED_KSK_TEST: ShortintKeySwitchingKeyTest = ShortintKeySwitchingKeyTest {
+    test_filename: Cow::Borrowed("compressed_keyswitching_key"),
+    input_key_filename: Cow::Borrowed(SHORTINT_CLIENT_KEY_FILENAME),
+    output_key_filename: Cow::Borrowed(SHORTINT_KSK_OUTPUT_CLIENT_KEY_FILENAME),
+    compressed: true,
+    ks_base_log: 5,
+    ks_level: 3,
+    destination_key: Cow::Borrowed("small"),
+    clear_value: 0,
+};
+
+// Integer
+const INTEGER_CLIENT_KEY_FILENAME: &str = "client_key";
+
+const INTEGER_CLIENTKEY_TEST: IntegerClientKeyTest = IntegerClientKeyTest {
+    test_filename: Cow::Borrowed(INTEGER_CLIENT_KEY_FILENAME),
+    parameters: VALID_TEST_PARAMS_TUNIFORM,
+};
+
+const INTEGER_RADIX_CT_TEST: IntegerRadixCiphertextTest = IntegerRadixCiphertextTest {
+    test_filename: Cow::Borrowed("radix_ciphertext"),
+    key_filename: Cow::Borrowed(INTEGER_CLIENT_KEY_FILENAME),
+    num_blocks: 4,
+    clear_value: 187,
+};
+
+const INTEGER_SIGNED_RADIX_CT_TEST: IntegerSignedRadixCiphertextTest =
+    IntegerSignedRadixCiphertextTest {
+        test_filename: Cow::Borrowed("signed_radix_ciphertext"),
+        key_filename: Cow::Borrowed(INTEGER_CLIENT_KEY_FILENAME),
+        num_blocks: 4,
+        clear_value: -37,
+    };
+
+const INTEGER_CRT_CT_TEST: IntegerCrtCiphertextTest = IntegerCrtCiphertextTest {
+    test_filename: Cow::Borrowed("crt_ciphertext"),
+    key_filename: Cow::Borrowed(INTEGER_CLIENT_KEY_FILENAME),
+    crt_bases: Cow::Borrowed(&[2, 3, 5]),
+    clear_value: 23,
+};
+
+const INTEGER_BOOLEAN_BLOCK_TEST: IntegerBooleanBlockTest = IntegerBooleanBlockTest {
+    test_filename: Cow::Borrowed("boolean_block"),
+    key_filename: Cow::Borrowed(INTEGER_CLIENT_KEY_FILENAME),
+    clear_value: true,
+};
+
+// Core crypto
+const CORE_CRYPTO_LWE_SECRET_KEY_FILENAME: &str = "lwe_secret_key";
+const CORE_CRYPTO_SMALL_LWE_SECRET_KEY_FILENAME: &str = "small_lwe_secret_key";
+const CORE_CRYPTO_GLWE_SECRET_KEY_FILENAME: &str = "glwe_secret_key";
+
+// The compact public key needs a power of two lwe dimension
+const CORE_CRYPTO_LWE_DIMENSION: usize = 512;
+// WARNING: this dimension is completely insecure, it is only used to keep the bootstrapping and
+// packing keys small
+const CORE_CRYPTO_SMALL_LWE_DIMENSION: usize = 16;
+const CORE_CRYPTO_GLWE_DIMENSION: usize = 1;
+const CORE_CRYPTO_POLYNOMIAL_SIZE: usize = 256;
+const CORE_CRYPTO_CIPHERTEXT_MODULUS: u128 = 1 << 64;
+const CORE_CRYPTO_NOISE_DISTRIBUTION: TestDistribution =
+    TestDistribution::TUniform { bound_log2: 20 };
+// 4 bits messages with a padding bit
+const CORE_CRYPTO_DELTA: u64 = 1 << 59;
+
+const CORE_CRYPTO_LWE_SECRET_KEY_TEST: CoreCryptoLweSecretKeyTest = CoreCryptoLweSecretKeyTest {
+    test_filename: Cow::Borrowed(CORE_CRYPTO_LWE_SECRET_KEY_FILENAME),
+    lwe_dimension: CORE_CRYPTO_LWE_DIMENSION,
+};
+
+const CORE_CRYPTO_SMALL_LWE_SECRET_KEY_TEST: CoreCryptoLweSecretKeyTest =
+    CoreCryptoLweSecretKeyTest {
+        test_filename: Cow::Borrowed(CORE_CRYPTO_SMALL_LWE_SECRET_KEY_FILENAME),
+        lwe_dimension: CORE_CRYPTO_SMALL_LWE_DIMENSION,
+    };
+
+const CORE_CRYPTO_GLWE_SECRET_KEY_TEST: CoreCryptoGlweSecretKeyTest = CoreCryptoGlweSecretKeyTest {
+    test_filename: Cow::Borrowed(CORE_CRYPTO_GLWE_SECRET_KEY_FILENAME),
+    glwe_dimension: CORE_CRYPTO_GLWE_DIMENSION,
+    polynomial_size: CORE_CRYPTO_POLYNOMIAL_SIZE,
+};
+
+const CORE_CRYPTO_KSK_TEST: CoreCryptoLweKeyswitchKeyTest = CoreCryptoLweKeyswitchKeyTest {
+    test_filename: Cow::Borrowed("lwe_keyswitch_key"),
+    input_key_filename: Cow::Borrowed(CORE_CRYPTO_LWE_SECRET_KEY_FILENAME),
+    output_key_filename: Cow::Borrowed(CORE_CRYPTO_SMALL_LWE_SECRET_KEY_FILENAME),
+    input_lwe_dimension: CORE_CRYPTO_LWE_DIMENSION,
+    output_lwe_dimension: CORE_CRYPTO_SMALL_LWE_DIMENSION,
+    decomp_base_log: 4,
+    decomp_level_count: 5,
+    ciphertext_modulus: CORE_CRYPTO_CIPHERTEXT_MODULUS,
+    delta: CORE_CRYPTO_DELTA,
+    clear_value: 6,
+};
+
+const CORE_CRYPTO_BSK_TEST: CoreCryptoLweBootstrapKeyTest = CoreCryptoLweBootstrapKeyTest {
+    test_filename: Cow::Borrowed("lwe_bootstrap_key"),
+    input_key_filename: Cow::Borrowed(CORE_CRYPTO_SMALL_LWE_SECRET_KEY_FILENAME),
+    output_key_filename: Cow::Borrowed(CORE_CRYPTO_GLWE_SECRET_KEY_FILENAME),
+    input_lwe_dimension: CORE_CRYPTO_SMALL_LWE_DIMENSION,
+    glwe_dimension: CORE_CRYPTO_GLWE_DIMENSION,
+    polynomial_size: CORE_CRYPTO_POLYNOMIAL_SIZE,
+    decomp_base_log: 15,
+    decomp_level_count: 2,
+    ciphertext_modulus: CORE_CRYPTO_CIPHERTEXT_MODULUS,
+    fourier: false,
+    delta: CORE_CRYPTO_DELTA,
+    clear_value: 3,
+};
+
+const CORE_CRYPTO_FOURIER_BSK_TEST: CoreCryptoLweBootstrapKeyTest = CoreCryptoLweBootstrapKeyTest {
+    test_filename: Cow::Borrowed("fourier_lwe_bootstrap_key"),
+    input_key_filename: CORE_CRYPTO_BSK_TEST.input_key_filename,
+    output_key_filename: CORE_CRYPTO_BSK_TEST.output_key_filename,
+    input_lwe_dimension: CORE_CRYPTO_BSK_TEST.input_lwe_dimension,
+    glwe_dimension: CORE_CRYPTO_BSK_TEST.glwe_dimension,
+    polynomial_size: CORE_CRYPTO_BSK_TEST.polynomial_size,
+    decomp_base_log: CORE_CRYPTO_BSK_TEST.decomp_base_log,
+    decomp_level_count: CORE_CRYPTO_BSK_TEST.decomp_level_count,
+    ciphertext_modulus: CORE_CRYPTO_BSK_TEST.ciphertext_modulus,
+    fourier: true,
+    delta: CORE_CRYPTO_DELTA,
+    clear_value: 13,
+};
+
+const CORE_CRYPTO_MULTI_BIT_BSK_TEST: CoreCryptoLweMultiBitBootstrapKeyTest =
+    CoreCryptoLweMultiBitBootstrapKeyTest {
+        test_filename: Cow::Borrowed("lwe_multi_bit_bootstrap_key"),
+        input_key_filename: Cow::Borrowed(CORE_CRYPTO_SMALL_LWE_SECRET_KEY_FILENAME),
+        output_key_filename: Cow::Borrowed(CORE_CRYPTO_GLWE_SECRET_KEY_FILENAME),
+        input_lwe_dimension: CORE_CRYPTO_SMALL_LWE_DIMENSION,
+        glwe_dimension: CORE_CRYPTO_GLWE_DIMENSION,
+        polynomial_size: CORE_CRYPTO_POLYNOMIAL_SIZE,
+        decomp_base_log: 15,
+        decomp_level_count: 2,
+        grouping_factor: 2,
+        ciphertext_modulus: CORE_CRYPTO_CIPHERTEXT_MODULUS,
+        delta: CORE_CRYPTO_DELTA,
+        clear_value: 9,
+    };
+
+const CORE_CRYPTO_PKSK_TEST: CoreCryptoLwePackingKeyswitchKeyTest =
+    CoreCryptoLwePackingKeyswitchKeyTest {
+        test_filename: Cow::Borrowed("lwe_packing_keyswitch_key"),
+        input_key_filename: Cow::Borrowed(CORE_CRYPTO_SMALL_LWE_SECRET_KEY_FILENAME),
+        output_key_filename: Cow::Borrowed(CORE_CRYPTO_GLWE_SECRET_KEY_FILENAME),
+        input_lwe_dimension: CORE_CRYPTO_SMALL_LWE_DIMENSION,
+        output_glwe_dimension: CORE_CRYPTO_GLWE_DIMENSION,
+        output_polynomial_size: CORE_CRYPTO_POLYNOMIAL_SIZE,
+        decomp_base_log: 8,
+        decomp_level_count: 3,
+        ciphertext_modulus: CORE_CRYPTO_CIPHERTEXT_MODULUS,
+        delta: CORE_CRYPTO_DELTA,
+        clear_value: 10,
+    };
+
+const CORE_CRYPTO_COMPACT_PK_TEST: CoreCryptoLweCompactPublicKeyTest =
+    CoreCryptoLweCompactPublicKeyTest {
+        test_filename: Cow::Borrowed("lwe_compact_public_key"),
+        key_filename: Cow::Borrowed(CORE_CRYPTO_LWE_SECRET_KEY_FILENAME),
+        lwe_dimension: CORE_CRYPTO_LWE_DIMENSION,
+        ciphertext_modulus: CORE_CRYPTO_CIPHERTEXT_MODULUS,
+        delta: CORE_CRYPTO_DELTA,
+        clear_value: 4,
+    };
+
+const CORE_CRYPTO_LWE_CT_TEST: CoreCryptoLweCiphertextTest = CoreCryptoLweCiphertextTest {
+    test_filename: Cow::Borrowed("lwe_ciphertext"),
+    key_filename: Cow::Borrowed(CORE_CRYPTO_LWE_SECRET_KEY_FILENAME),
+    lwe_dimension: CORE_CRYPTO_LWE_DIMENSION,
+    ciphertext_modulus: CORE_CRYPTO_CIPHERTEXT_MODULUS,
+    delta: CORE_CRYPTO_DELTA,
+    clear_value: 11,
+};
+
+const CORE_CRYPTO_LWE_CT_LIST_TEST: CoreCryptoLweCiphertextListTest =
+    CoreCryptoLweCiphertextListTest {
+        test_filename: Cow::Borrowed("lwe_ciphertext_list"),
+        key_filename: Cow::Borrowed(CORE_CRYPTO_LWE_SECRET_KEY_FILENAME),
+        lwe_dimension: CORE_CRYPTO_LWE_DIMENSION,
+        ciphertext_modulus: CORE_CRYPTO_CIPHERTEXT_MODULUS,
+        delta: CORE_CRYPTO_DELTA,
+        clear_values: Cow::Borrowed(&[1, 5, 9, 14]),
+    };
+
+const CORE_CRYPTO_LWE_COMPACT_CT_LIST_TEST: CoreCryptoLweCompactCiphertextListTest =
+    CoreCryptoLweCompactCiphertextListTest {
+        test_filename: Cow::Borrowed("lwe_compact_ciphertext_list"),
+        key_filename: Cow::Borrowed(CORE_CRYPTO_LWE_SECRET_KEY_FILENAME),
+        lwe_dimension: CORE_CRYPTO_LWE_DIMENSION,
+        ciphertext_modulus: CORE_CRYPTO_CIPHERTEXT_MODULUS,
+        delta: CORE_CRYPTO_DELTA,
+        clear_values: Cow::Borrowed(&[7, 0, 3, 12, 15]),
+    };
+
+const CORE_CRYPTO_GLWE_CT_TEST: CoreCryptoGlweCiphertextTest = CoreCryptoGlweCiphertextTest {
+    test_filename: Cow::Borrowed("glwe_ciphertext"),
+    key_filename: Cow::Borrowed(CORE_CRYPTO_GLWE_SECRET_KEY_FILENAME),
+    glwe_dimension: CORE_CRYPTO_GLWE_DIMENSION,
+    polynomial_size: CORE_CRYPTO_POLYNOMIAL_SIZE,
+    ciphertext_modulus: CORE_CRYPTO_CIPHERTEXT_MODULUS,
+    delta: CORE_CRYPTO_DELTA,
+    // The other coefficients of the polynomial are 0
+    clear_values: Cow::Borrowed(&[2, 13, 8, 4]),
+};
+
+const CORE_CRYPTO_GGSW_CT_TEST: CoreCryptoGgswCiphertextTest = CoreCryptoGgswCiphertextTest {
+    test_filename: Cow::Borrowed("ggsw_ciphertext"),
+    key_filename: Cow::Borrowed(CORE_CRYPTO_GLWE_SECRET_KEY_FILENAME),
+    glwe_dimension: CORE_CRYPTO_GLWE_DIMENSION,
+    polynomial_size: CORE_CRYPTO_POLYNOMIAL_SIZE,
+    decomp_base_log: 8,
+    decomp_level_count: 3,
+    ciphertext_modulus: CORE_CRYPTO_CIPHERTEXT_MODULUS,
+    clear_value: 1,
+};
+
+// The seeded entities encrypt the same values and use the same parameters as their regular
+// counterpart. Their metadata holds the digest of their decompressed form, so it is built during
+// the generation.
+const CORE_CRYPTO_SEEDED_LWE_CT_FILENAME: &str = "seeded_lwe_ciphertext";
+const CORE_CRYPTO_SEEDED_GLWE_CT_FILENAME: &str = "seeded_glwe_ciphertext";
+const CORE_CRYPTO_SEEDED_KSK_FILENAME: &str = "seeded_lwe_keyswitch_key";
+const CORE_CRYPTO_SEEDED_BSK_FILENAME: &str = "seeded_lwe_bootstrap_key";
+const CORE_CRYPTO_SEEDED_COMPACT_PK_FILENAME: &str = "seeded_lwe_compact_public_key";
+
+// Boolean
+const BOOLEAN_CLIENT_KEY_FILENAME: &str = "client_key";
+
+// WARNING: these parameters are completely insecure, they are only used to keep the keys small
+const BOOLEAN_CLIENTKEY_TEST: BooleanClientKeyTest = BooleanClientKeyTest {
+    test_filename: Cow::Borrowed(BOOLEAN_CLIENT_KEY_FILENAME),
+    parameters: INSECURE_SMALL_BOOLEAN_TEST_PARAMS,
+};
+
+const BOOLEAN_SERVERKEY_TEST: BooleanServerKeyTest = BooleanServerKeyTest {
+    test_filename: Cow::Borrowed("server_key"),
+    client_key_filename: Cow::Borrowed(BOOLEAN_CLIENT_KEY_FILENAME),
+    compressed: false,
+};
+
+const BOOLEAN_COMPRESSED_SERVERKEY_TEST: BooleanServerKeyTest = BooleanServerKeyTest {
+    test_filename: Cow::Borrowed("compressed_server_key"),
+    client_key_filename: Cow::Borrowed(BOOLEAN_CLIENT_KEY_FILENAME),
+    compressed: true,
+};
+
+const BOOLEAN_PUBKEY_TEST: BooleanPublicKeyTest = BooleanPublicKeyTest {
+    test_filename: Cow::Borrowed("public_key"),
+    client_key_filename: Cow::Borrowed(BOOLEAN_CLIENT_KEY_FILENAME),
+    compressed: false,
+};
+
+const BOOLEAN_COMPRESSED_PUBKEY_TEST: BooleanPublicKeyTest = BooleanPublicKeyTest {
+    test_filename: Cow::Borrowed("compressed_public_key"),
+    client_key_filename: Cow::Borrowed(BOOLEAN_CLIENT_KEY_FILENAME),
+    compressed: true,
+};
+
+const BOOLEAN_CT_TEST: BooleanCiphertextTest = BooleanCiphertextTest {
+    test_filename: Cow::Borrowed("ciphertext"),
+    key_filename: Cow::Borrowed(BOOLEAN_CLIENT_KEY_FILENAME),
+    compressed: false,
+    clear_value: true,
+};
+
+const BOOLEAN_COMPRESSED_CT_TEST: BooleanCiphertextTest = BooleanCiphertextTest {
+    test_filename: Cow::Borrowed("compressed_ciphertext"),
+    key_filename: Cow::Borrowed(BOOLEAN_CLIENT_KEY_FILENAME),
+    compressed: true,
+    clear_value: false,
+};
+
 pub struct V0_11;
 
 impl TfhersVersion for V0_11 {
     const VERSION_NUMBER: &'static str = "0.11";
+    const FROZEN: bool = true;
 
     fn seed_prng(seed: u128) {
         let mut seeder = DeterministicSeeder::<DefaultRandomGenerator>::new(Seed(seed));
@@ -140,12 +740,268 @@ impl TfhersVersion for V0_11 {
         BooleanEngine::replace_thread_local(boolean_engine);
     }
 
-    fn gen_shortint_data() -> Vec<TestMetadata> {
-        Vec::new()
+    fn legacy_module_generators() -> Vec<ModuleGenerator> {
+        vec![ModuleGenerator::new(
+            HL_MODULE_NAME,
+            Self::gen_legacy_hl_data,
+        )]
+    }
+
+    fn module_generators() -> Vec<ModuleGenerator> {
+        vec![
+            ModuleGenerator::new(CORE_CRYPTO_MODULE_NAME, Self::gen_core_crypto_data),
+            ModuleGenerator::new(BOOLEAN_MODULE_NAME, Self::gen_boolean_data),
+            ModuleGenerator::new(SHORTINT_MODULE_NAME, Self::gen_shortint_data),
+            ModuleGenerator::new(INTEGER_MODULE_NAME, Self::gen_integer_data),
+            ModuleGenerator::new(HL_MODULE_NAME, Self::gen_hl_data),
+            ModuleGenerator::new(ZK_MODULE_NAME, Self::gen_zk_data),
+        ]
+    }
+}
+
+impl V0_11 {
+    fn gen_shortint_data(base_data_dir: &Path) -> Vec<TestMetadata> {
+        let dir = Self::data_dir(base_data_dir).join(SHORTINT_MODULE_NAME);
+        create_dir_all(&dir).unwrap();
+
+        let seed_test =
+            |test_filename: &str| Self::seed_prng_for_test(SHORTINT_MODULE_NAME, test_filename);
+
+        // generate keys
+        seed_test(&SHORTINT_CLIENTKEY_TEST.test_filename);
+        let shortint_client_key = shortint::ClientKey::new(SHORTINT_CLIENTKEY_TEST.parameters);
+        seed_test(&SHORTINT_SERVERKEY_TEST.test_filename);
+        let shortint_server_key = shortint::ServerKey::new(&shortint_client_key);
+        seed_test(&SHORTINT_COMPRESSED_SERVERKEY_TEST.test_filename);
+        let shortint_compressed_server_key =
+            shortint::CompressedServerKey::new(&shortint_client_key);
+
+        store_versioned_test!(
+            &shortint_client_key,
+            &dir,
+            &SHORTINT_CLIENTKEY_TEST.test_filename,
+        );
+        store_versioned_test!(
+            &shortint_server_key,
+            &dir,
+            &SHORTINT_SERVERKEY_TEST.test_filename,
+        );
+        store_versioned_test!(
+            &shortint_compressed_server_key,
+            &dir,
+            &SHORTINT_COMPRESSED_SERVERKEY_TEST.test_filename,
+        );
+
+        // generate compressed ciphertexts
+        seed_test(&SHORTINT_COMPRESSED_CT_TEST.test_filename);
+        let compressed_ct =
+            shortint_client_key.encrypt_compressed(SHORTINT_COMPRESSED_CT_TEST.clear_value);
+        seed_test(&SHORTINT_COMPRESSED_CT_MODSWITCHED_TEST.test_filename);
+        let compressed_ct_modswitched = shortint_server_key.switch_modulus_and_compress(
+            &shortint_client_key.encrypt(SHORTINT_COMPRESSED_CT_MODSWITCHED_TEST.clear_value),
+        );
+
+        store_versioned_test!(
+            &compressed_ct,
+            &dir,
+            &SHORTINT_COMPRESSED_CT_TEST.test_filename,
+        );
+        store_versioned_test!(
+            &compressed_ct_modswitched,
+            &dir,
+            &SHORTINT_COMPRESSED_CT_MODSWITCHED_TEST.test_filename,
+        );
+
+        // generate ciphertexts
+        seed_test(&SHORTINT_TUNIFORM_CLIENTKEY_TEST.test_filename);
+        let shortint_tuniform_client_key =
+            shortint::ClientKey::new(SHORTINT_TUNIFORM_CLIENTKEY_TEST.parameters);
+        seed_test(&SHORTINT_CT1_TEST.test_filename);
+        let ct1 = shortint_tuniform_client_key.encrypt(SHORTINT_CT1_TEST.clear_value);
+        seed_test(&SHORTINT_CT2_TEST.test_filename);
+        let ct2 = shortint_tuniform_client_key.encrypt(SHORTINT_CT2_TEST.clear_value);
+
+        store_versioned_test!(
+            &shortint_tuniform_client_key,
+            &dir,
+            &SHORTINT_TUNIFORM_CLIENTKEY_TEST.test_filename,
+        );
+        store_versioned_test!(&ct1, &dir, &SHORTINT_CT1_TEST.test_filename);
+        store_versioned_test!(&ct2, &dir, &SHORTINT_CT2_TEST.test_filename);
+
+        // generate public keys
+        seed_test(&SHORTINT_PUBKEY_TEST.test_filename);
+        let shortint_pub_key = shortint::PublicKey::new(&shortint_client_key);
+        seed_test(&SHORTINT_COMPRESSED_PUBKEY_TEST.test_filename);
+        let shortint_compressed_pub_key = shortint::CompressedPublicKey::new(&shortint_client_key);
+        seed_test(&SHORTINT_COMPACT_PUBKEY_TEST.test_filename);
+        let shortint_compact_pub_key =
+            shortint::CompactPublicKey::new(&shortint_tuniform_client_key);
+        seed_test(&SHORTINT_COMPRESSED_COMPACT_PUBKEY_TEST.test_filename);
+        let shortint_compressed_compact_pub_key =
+            shortint::CompressedCompactPublicKey::new(&shortint_tuniform_client_key);
+
+        store_versioned_test!(&shortint_pub_key, &dir, &SHORTINT_PUBKEY_TEST.test_filename,);
+        store_versioned_test!(
+            &shortint_compressed_pub_key,
+            &dir,
+            &SHORTINT_COMPRESSED_PUBKEY_TEST.test_filename,
+        );
+        store_versioned_test!(
+            &shortint_compact_pub_key,
+            &dir,
+            &SHORTINT_COMPACT_PUBKEY_TEST.test_filename,
+        );
+        store_versioned_test!(
+            &shortint_compressed_compact_pub_key,
+            &dir,
+            &SHORTINT_COMPRESSED_COMPACT_PUBKEY_TEST.test_filename,
+        );
+
+        // generate compact lists
+        seed_test(&SHORTINT_COMPACT_LIST_TEST.test_filename);
+        let compact_list =
+            shortint_compact_pub_key.encrypt_slice(&SHORTINT_COMPACT_LIST_TEST.clear_values);
+        seed_test(&SHORTINT_PACKED_COMPACT_LIST_TEST.test_filename);
+        let packed_compact_list = shortint_compact_pub_key.encrypt_iter_with_modulus(
+            SHORTINT_PACKED_COMPACT_LIST_TEST
+                .clear_values
+                .iter()
+                .copied(),
+            (SHORTINT_TUNIFORM_CLIENTKEY_TEST.parameters.message_modulus
+                * SHORTINT_TUNIFORM_CLIENTKEY_TEST.parameters.carry_modulus) as u64,
+        );
+
+        store_versioned_test!(
+            &compact_list,
+            &dir,
+            &SHORTINT_COMPACT_LIST_TEST.test_filename,
+        );
+        store_versioned_test!(
+            &packed_compact_list,
+            &dir,
+            &SHORTINT_PACKED_COMPACT_LIST_TEST.test_filename,
+        );
+
+        // generate keyswitching keys
+        seed_test(&SHORTINT_KSK_OUTPUT_CLIENTKEY_TEST.test_filename);
+        let shortint_ksk_output_client_key =
+            shortint::ClientKey::new(SHORTINT_KSK_OUTPUT_CLIENTKEY_TEST.parameters);
+        // The output server keys are stored inside the keyswitching keys
+        seed_test(&SHORTINT_KSK_TEST.test_filename);
+        let ksk = shortint::KeySwitchingKey::new(
+            (&shortint_client_key, Some(&shortint_server_key)),
+            (
+                &shortint_ksk_output_client_key,
+                &shortint::ServerKey::new(&shortint_ksk_output_client_key),
+            ),
+            (&SHORTINT_KSK_TEST).into(),
+        );
+        seed_test(&SHORTINT_COMPRESSED_KSK_TEST.test_filename);
+        let compressed_ksk = shortint::key_switching_key::CompressedKeySwitchingKey::new(
+            (&shortint_client_key, Some(&shortint_compressed_server_key)),
+            (
+                &shortint_ksk_output_client_key,
+                &shortint::CompressedServerKey::new(&shortint_ksk_output_client_key),
+            ),
+            (&SHORTINT_COMPRESSED_KSK_TEST).into(),
+        );
+
+        store_versioned_test!(
+            &shortint_ksk_output_client_key,
+            &dir,
+            &SHORTINT_KSK_OUTPUT_CLIENTKEY_TEST.test_filename,
+        );
+        store_versioned_test!(&ksk, &dir, &SHORTINT_KSK_TEST.test_filename);
+        store_versioned_test!(
+            &compressed_ksk,
+            &dir,
+            &SHORTINT_COMPRESSED_KSK_TEST.test_filename,
+        );
+
+        vec![
+            TestMetadata::ShortintClientKey(SHORTINT_CLIENTKEY_TEST),
+            TestMetadata::ShortintServerKey(SHORTINT_SERVERKEY_TEST),
+            TestMetadata::ShortintServerKey(SHORTINT_COMPRESSED_SERVERKEY_TEST),
+            TestMetadata::ShortintCompressedCiphertext(SHORTINT_COMPRESSED_CT_TEST),
+            TestMetadata::ShortintCompressedCiphertext(SHORTINT_COMPRESSED_CT_MODSWITCHED_TEST),
+            TestMetadata::ShortintClientKey(SHORTINT_TUNIFORM_CLIENTKEY_TEST),
+            TestMetadata::ShortintCiphertext(SHORTINT_CT1_TEST),
+            TestMetadata::ShortintCiphertext(SHORTINT_CT2_TEST),
+            TestMetadata::ShortintPublicKey(SHORTINT_PUBKEY_TEST),
+            TestMetadata::ShortintPublicKey(SHORTINT_COMPRESSED_PUBKEY_TEST),
+            TestMetadata::ShortintPublicKey(SHORTINT_COMPACT_PUBKEY_TEST),
+            TestMetadata::ShortintPublicKey(SHORTINT_COMPRESSED_COMPACT_PUBKEY_TEST),
+            TestMetadata::ShortintCompactCiphertextList(SHORTINT_COMPACT_LIST_TEST),
+            TestMetadata::ShortintCompactCiphertextList(SHORTINT_PACKED_COMPACT_LIST_TEST),
+            TestMetadata::ShortintClientKey(SHORTINT_KSK_OUTPUT_CLIENTKEY_TEST),
+            TestMetadata::ShortintKeySwitchingKey(SHORTINT_KSK_TEST),
+            TestMetadata::ShortintKeySwitchingKey(SHORTINT_COMPRESSED_KSK_TEST),
+        ]
+    }
+
+    fn gen_integer_data(base_data_dir: &Path) -> Vec<TestMetadata> {
+        let dir = Self::data_dir(base_data_dir).join(INTEGER_MODULE_NAME);
+        create_dir_all(&dir).unwrap();
+
+        let seed_test =
+            |test_filename: &str| Self::seed_prng_for_test(INTEGER_MODULE_NAME, test_filename);
+
+        // generate a client key
+        seed_test(&INTEGER_CLIENTKEY_TEST.test_filename);
+        let params: PBSParameters = INTEGER_CLIENTKEY_TEST.parameters.into();
+        let integer_client_key = integer::ClientKey::new(params);
+
+        store_versioned_test!(
+            &integer_client_key,
+            &dir,
+            &INTEGER_CLIENTKEY_TEST.test_filename,
+        );
+
+        // generate ciphertexts
+        seed_test(&INTEGER_RADIX_CT_TEST.test_filename);
+        let radix_ct = integer_client_key.encrypt_radix(
+            INTEGER_RADIX_CT_TEST.clear_value,
+            INTEGER_RADIX_CT_TEST.num_blocks,
+        );
+        seed_test(&INTEGER_SIGNED_RADIX_CT_TEST.test_filename);
+        let signed_radix_ct = integer_client_key.encrypt_signed_radix(
+            INTEGER_SIGNED_RADIX_CT_TEST.clear_value,
+            INTEGER_SIGNED_RADIX_CT_TEST.num_blocks,
+        );
+        seed_test(&INTEGER_CRT_CT_TEST.test_filename);
+        let crt_ct = integer_client_key.encrypt_crt(
+            INTEGER_CRT_CT_TEST.clear_value,
+            INTEGER_CRT_CT_TEST.crt_bases.to_vec(),
+        );
+        seed_test(&INTEGER_BOOLEAN_BLOCK_TEST.test_filename);
+        let boolean_block = integer_client_key.encrypt_bool(INTEGER_BOOLEAN_BLOCK_TEST.clear_value);
+
+        // Serialize them
+        store_versioned_test!(&radix_ct, &dir, &INTEGER_RADIX_CT_TEST.test_filename);
+        store_versioned_test!(
+            &signed_radix_ct,
+            &dir,
+            &INTEGER_SIGNED_RADIX_CT_TEST.test_filename
+        );
+        store_versioned_test!(&crt_ct, &dir, &INTEGER_CRT_CT_TEST.test_filename);
+        store_versioned_test!(
+            &boolean_block,
+            &dir,
+            &INTEGER_BOOLEAN_BLOCK_TEST.test_filename
+        );
+
+        vec![
+            TestMetadata::IntegerClientKey(INTEGER_CLIENTKEY_TEST),
+            TestMetadata::IntegerRadixCiphertext(INTEGER_RADIX_CT_TEST),
+            TestMetadata::IntegerSignedRadixCiphertext(INTEGER_SIGNED_RADIX_CT_TEST),
+            TestMetadata::IntegerCrtCiphertext(INTEGER_CRT_CT_TEST),
+            TestMetadata::IntegerBooleanBlock(INTEGER_BOOLEAN_BLOCK_TEST),
+        ]
     }
 
-    fn gen_hl_data() -> Vec<TestMetadata> {
-        let dir = Self::data_dir().join(HL_MODULE_NAME);
+    fn gen_legacy_hl_data(base_data_dir: &Path) -> Vec<TestMetadata> {
+        let dir = Self::data_dir(base_data_dir).join(HL_MODULE_NAME);
         create_dir_all(&dir).unwrap();
 
         let mut zk_rng: RandomGenerator<DefaultRandomGenerator> =
@@ -170,7 +1026,15 @@ impl TfhersVersion for V0_11 {
         )
         .unwrap();
 
-        store_versioned_test!(&crs, &dir, &ZK_PKE_CRS_TEST.test_filename,);
+        // The CRS is tested in the zk module, it is only stored here to verify the proof of the list
+        store_versioned_auxiliary!(
+            &crs,
+            &dir,
+            &HL_PROVEN_COMPACTLIST_TEST_ZKV2
+                .proof_info
+                .unwrap()
+                .params_filename
+        );
 
         // Store the associated client key to be able to decrypt the ciphertexts in the list
         store_versioned_auxiliary!(
@@ -213,9 +1077,661 @@ impl TfhersVersion for V0_11 {
             &HL_PROVEN_COMPACTLIST_TEST_ZKV2.test_filename,
         );
 
+        vec![TestMetadata::HlHeterogeneousCiphertextList(
+            HL_PROVEN_COMPACTLIST_TEST_ZKV2,
+        )]
+    }
+
+    fn gen_hl_data(base_data_dir: &Path) -> Vec<TestMetadata> {
+        let dir = Self::data_dir(base_data_dir).join(HL_MODULE_NAME);
+        create_dir_all(&dir).unwrap();
+
+        let seed_test =
+            |test_filename: &str| Self::seed_prng_for_test(HL_MODULE_NAME, test_filename);
+
+        // The client key of the legacy tests can only be generated by the legacy generators, so
+        // these ciphertexts are encrypted with their own key.
+        let config =
+            tfhe_0_11::ConfigBuilder::with_custom_parameters(HL_CLIENTKEY_TEST.parameters).build();
+        seed_test(HL_WIDE_INTEGERS_CLIENT_KEY_FILENAME);
+        let hl_client_key = ClientKey::generate(config);
+
+        store_versioned_auxiliary!(&hl_client_key, &dir, HL_WIDE_INTEGERS_CLIENT_KEY_FILENAME);
+
+        // Generate ciphertexts for a range of integer widths
+        seed_test(&HL_UINT4_CT_TEST.test_filename);
+        let ct_uint4 =
+            FheUint4::encrypt(HL_UINT4_CT_TEST.clear_value.low_u64() as u8, &hl_client_key);
+        store_versioned_test!(&ct_uint4, &dir, &HL_UINT4_CT_TEST.test_filename);
+        seed_test(&HL_UINT16_CT_TEST.test_filename);
+        let ct_uint16 = FheUint16::encrypt(
+            HL_UINT16_CT_TEST.clear_value.low_u64() as u16,
+            &hl_client_key,
+        );
+        store_versioned_test!(&ct_uint16, &dir, &HL_UINT16_CT_TEST.test_filename);
+        seed_test(&HL_UINT32_CT_TEST.test_filename);
+        let ct_uint32 = FheUint32::encrypt(
+            HL_UINT32_CT_TEST.clear_value.low_u64() as u32,
+            &hl_client_key,
+        );
+        store_versioned_test!(&ct_uint32, &dir, &HL_UINT32_CT_TEST.test_filename);
+        seed_test(&HL_UINT64_CT_TEST.test_filename);
+        let ct_uint64 = FheUint64::encrypt(HL_UINT64_CT_TEST.clear_value.low_u64(), &hl_client_key);
+        store_versioned_test!(&ct_uint64, &dir, &HL_UINT64_CT_TEST.test_filename);
+        seed_test(&HL_UINT128_CT_TEST.test_filename);
+        let ct_uint128 =
+            FheUint128::encrypt(HL_UINT128_CT_TEST.clear_value.low_u128(), &hl_client_key);
+        store_versioned_test!(&ct_uint128, &dir, &HL_UINT128_CT_TEST.test_filename);
+        seed_test(&HL_UINT160_CT_TEST.test_filename);
+        let ct_uint160 = FheUint160::encrypt(
+            U256::from((
+                HL_UINT160_CT_TEST.clear_value.low_u128(),
+                HL_UINT160_CT_TEST.clear_value.high_u128(),
+            )),
+            &hl_client_key,
+        );
+        store_versioned_test!(&ct_uint160, &dir, &HL_UINT160_CT_TEST.test_filename);
+        seed_test(&HL_UINT256_CT_TEST.test_filename);
+        let ct_uint256 = FheUint256::encrypt(
+            U256::from((
+                HL_UINT256_CT_TEST.clear_value.low_u128(),
+                HL_UINT256_CT_TEST.clear_value.high_u128(),
+            )),
+            &hl_client_key,
+        );
+        store_versioned_test!(&ct_uint256, &dir, &HL_UINT256_CT_TEST.test_filename);
+        seed_test(&HL_INT16_CT_TEST.test_filename);
+        let ct_int16 = FheInt16::encrypt(
+            HL_INT16_CT_TEST.clear_value.low_u64() as i16,
+            &hl_client_key,
+        );
+        store_versioned_test!(&ct_int16, &dir, &HL_INT16_CT_TEST.test_filename);
+        seed_test(&HL_INT64_CT_TEST.test_filename);
+        let ct_int64 = FheInt64::encrypt(
+            HL_INT64_CT_TEST.clear_value.low_u64() as i64,
+            &hl_client_key,
+        );
+        store_versioned_test!(&ct_int64, &dir, &HL_INT64_CT_TEST.test_filename);
+        seed_test(&HL_INT128_CT_TEST.test_filename);
+        let ct_int128 = FheInt128::encrypt(
+            HL_INT128_CT_TEST.clear_value.low_u128() as i128,
+            &hl_client_key,
+        );
+        store_versioned_test!(&ct_int128, &dir, &HL_INT128_CT_TEST.test_filename);
+        seed_test(&HL_INT160_CT_TEST.test_filename);
+        let ct_int160 = FheInt160::encrypt(
+            I256::from((
+                HL_INT160_CT_TEST.clear_value.low_u128(),
+                HL_INT160_CT_TEST.clear_value.high_u128(),
+            )),
+            &hl_client_key,
+        );
+        store_versioned_test!(&ct_int160, &dir, &HL_INT160_CT_TEST.test_filename);
+        seed_test(&HL_INT256_CT_TEST.test_filename);
+        let ct_int256 = FheInt256::encrypt(
+            I256::from((
+                HL_INT256_CT_TEST.clear_value.low_u128(),
+                HL_INT256_CT_TEST.clear_value.high_u128(),
+            )),
+            &hl_client_key,
+        );
+        store_versioned_test!(&ct_int256, &dir, &HL_INT256_CT_TEST.test_filename);
+
+        vec![
+            TestMetadata::HlWideCiphertext(HL_UINT4_CT_TEST),
+            TestMetadata::HlWideCiphertext(HL_UINT16_CT_TEST),
+            TestMetadata::HlWideCiphertext(HL_UINT32_CT_TEST),
+            TestMetadata::HlWideCiphertext(HL_UINT64_CT_TEST),
+            TestMetadata::HlWideCiphertext(HL_UINT128_CT_TEST),
+            TestMetadata::HlWideCiphertext(HL_UINT160_CT_TEST),
+            TestMetadata::HlWideCiphertext(HL_UINT256_CT_TEST),
+            TestMetadata::HlSignedWideCiphertext(HL_INT16_CT_TEST),
+            TestMetadata::HlSignedWideCiphertext(HL_INT64_CT_TEST),
+            TestMetadata::HlSignedWideCiphertext(HL_INT128_CT_TEST),
+            TestMetadata::HlSignedWideCiphertext(HL_INT160_CT_TEST),
+            TestMetadata::HlSignedWideCiphertext(HL_INT256_CT_TEST),
+        ]
+    }
+
+    fn gen_zk_data(base_data_dir: &Path) -> Vec<TestMetadata> {
+        let dir = Self::data_dir(base_data_dir).join(ZK_MODULE_NAME);
+        create_dir_all(&dir).unwrap();
+
+        // This CRS was first stored in the hl module, it is generated from the same seed so that it
+        // is not modified
+        let mut zk_rng: RandomGenerator<DefaultRandomGenerator> =
+            RandomGenerator::new(Seed(PRNG_SEED));
+        let crs = CompactPkeCrs::new(
+            LweDimension(ZK_PKE_CRS_TEST.lwe_dimension),
+            LweCiphertextCount(ZK_PKE_CRS_TEST.max_num_cleartext),
+            TUniform::<u64>::new(ZK_PKE_CRS_TEST.noise_bound as u32),
+            CiphertextModulus::new(ZK_PKE_CRS_TEST.ciphertext_modulus),
+            ZK_PKE_CRS_TEST.plaintext_modulus as u64,
+            ZkMSBZeroPaddingBitCount(ZK_PKE_CRS_TEST.padding_bit_count as u64),
+            &mut zk_rng,
+        )
+        .unwrap();
+
+        store_versioned_test!(&crs, &dir, &ZK_PKE_CRS_TEST.test_filename);
+
+        // Standalone proof of the encryption of a core_crypto compact list
+        let mut seeder = DeterministicSeeder::<DefaultRandomGenerator>::new(Seed(test_seed(
+            Self::VERSION_NUMBER,
+            ZK_MODULE_NAME,
+            &ZK_PKE_PROOF_ZKV2_TEST.test_filename,
+        )));
+        let mut secret_generator =
+            SecretRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed());
+        let mut encryption_generator =
+            EncryptionRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed(), &mut seeder);
+        let mut random_generator = RandomGenerator::<DefaultRandomGenerator>::new(seeder.seed());
+
+        let lwe_dimension = LweDimension(ZK_PKE_CRS_TEST.lwe_dimension);
+        let ciphertext_modulus = CiphertextModulus::new(ZK_PKE_CRS_TEST.ciphertext_modulus);
+        let noise_distribution = TUniform::<u64>::new(ZK_PKE_CRS_TEST.noise_bound as u32);
+
+        let secret_key =
+            allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+        let public_key = allocate_and_generate_new_lwe_compact_public_key(
+            &secret_key,
+            noise_distribution,
+            ciphertext_modulus,
+            &mut encryption_generator,
+        );
+
+        let mut ciphertext_list = LweCompactCiphertextList::new(
+            0u64,
+            lwe_dimension.to_lwe_size(),
+            LweCiphertextCount(ZK_PKE_PROOF_ZKV2_TEST.clear_values.len()),
+            ciphertext_modulus,
+        );
+
+        let proof = encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key(
+            &public_key,
+            &mut ciphertext_list,
+            &ZK_PKE_PROOF_ZKV2_TEST.clear_values.to_vec(),
+            ZK_PKE_PROOF_ZKV2_TEST.delta,
+            noise_distribution,
+            noise_distribution,
+            &mut secret_generator,
+            &mut encryption_generator,
+            &mut random_generator,
+            &crs,
+            ZK_PKE_PROOF_ZKV2_TEST.metadata.as_bytes(),
+            ZkComputeLoad::Proof,
+        )
+        .unwrap();
+
+        store_versioned_auxiliary!(&secret_key, &dir, &ZK_PKE_PROOF_ZKV2_TEST.key_filename);
+        store_versioned_auxiliary!(
+            &public_key,
+            &dir,
+            &ZK_PKE_PROOF_ZKV2_TEST.public_key_filename
+        );
+        store_versioned_auxiliary!(
+            &ciphertext_list,
+            &dir,
+            &ZK_PKE_PROOF_ZKV2_TEST.ciphertext_list_filename
+        );
+        store_versioned_test!(&proof, &dir, &ZK_PKE_PROOF_ZKV2_TEST.test_filename);
+
         vec![
             TestMetadata::ZkPkePublicParams(ZK_PKE_CRS_TEST),
-            TestMetadata::HlHeterogeneousCiphertextList(HL_PROVEN_COMPACTLIST_TEST_ZKV2),
+            TestMetadata::ZkPkeProof(ZK_PKE_PROOF_ZKV2_TEST),
+        ]
+    }
+
+    fn gen_core_crypto_data(base_data_dir: &Path) -> Vec<TestMetadata> {
+        let dir = Self::data_dir(base_data_dir).join(CORE_CRYPTO_MODULE_NAME);
+        create_dir_all(&dir).unwrap();
+
+        // core_crypto does not use the thread local engines, so each object gets its own seeder
+        let seeder_for_test = |test_filename: &str| {
+            DeterministicSeeder::<DefaultRandomGenerator>::new(Seed(test_seed(
+                Self::VERSION_NUMBER,
+                CORE_CRYPTO_MODULE_NAME,
+                test_filename,
+            )))
+        };
+        let encryption_generator_for_test = |test_filename: &str| {
+            let mut seeder = seeder_for_test(test_filename);
+            EncryptionRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed(), &mut seeder)
+        };
+        let secret_generator_for_test = |test_filename: &str| {
+            let mut seeder = seeder_for_test(test_filename);
+            SecretRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed())
+        };
+
+        let lwe_dimension = LweDimension(CORE_CRYPTO_LWE_DIMENSION);
+        let glwe_dimension = GlweDimension(CORE_CRYPTO_GLWE_DIMENSION);
+        let polynomial_size = PolynomialSize(CORE_CRYPTO_POLYNOMIAL_SIZE);
+        let ciphertext_modulus =
+            CiphertextModulus::try_new(CORE_CRYPTO_CIPHERTEXT_MODULUS).unwrap();
+        let noise_distribution: DynamicDistribution<u64> = CORE_CRYPTO_NOISE_DISTRIBUTION.into();
+
+        // generate the secret keys
+        let lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+            lwe_dimension,
+            &mut secret_generator_for_test(&CORE_CRYPTO_LWE_SECRET_KEY_TEST.test_filename),
+        );
+        let small_lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+            LweDimension(CORE_CRYPTO_SMALL_LWE_SECRET_KEY_TEST.lwe_dimension),
+            &mut secret_generator_for_test(&CORE_CRYPTO_SMALL_LWE_SECRET_KEY_TEST.test_filename),
+        );
+        let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+            glwe_dimension,
+            polynomial_size,
+            &mut secret_generator_for_test(&CORE_CRYPTO_GLWE_SECRET_KEY_TEST.test_filename),
+        );
+
+        store_versioned_test!(
+            &lwe_secret_key,
+            &dir,
+            &CORE_CRYPTO_LWE_SECRET_KEY_TEST.test_filename
+        );
+        store_versioned_test!(
+            &small_lwe_secret_key,
+            &dir,
+            &CORE_CRYPTO_SMALL_LWE_SECRET_KEY_TEST.test_filename
+        );
+        store_versioned_test!(
+            &glwe_secret_key,
+            &dir,
+            &CORE_CRYPTO_GLWE_SECRET_KEY_TEST.test_filename
+        );
+
+        // generate the keyswitching, bootstrapping and public keys
+        let ksk = allocate_and_generate_new_lwe_keyswitch_key(
+            &lwe_secret_key,
+            &small_lwe_secret_key,
+            DecompositionBaseLog(CORE_CRYPTO_KSK_TEST.decomp_base_log),
+            DecompositionLevelCount(CORE_CRYPTO_KSK_TEST.decomp_level_count),
+            noise_distribution,
+            ciphertext_modulus,
+            &mut encryption_generator_for_test(&CORE_CRYPTO_KSK_TEST.test_filename),
+        );
+
+        let bsk = allocate_and_generate_new_lwe_bootstrap_key(
+            &small_lwe_secret_key,
+            &glwe_secret_key,
+            DecompositionBaseLog(CORE_CRYPTO_BSK_TEST.decomp_base_log),
+            DecompositionLevelCount(CORE_CRYPTO_BSK_TEST.decomp_level_count),
+            noise_distribution,
+            ciphertext_modulus,
+            &mut encryption_generator_for_test(&CORE_CRYPTO_BSK_TEST.test_filename),
+        );
+
+        // The fourier key is converted from its own standard key
+        let bsk_for_fourier = allocate_and_generate_new_lwe_bootstrap_key(
+            &small_lwe_secret_key,
+            &glwe_secret_key,
+            DecompositionBaseLog(CORE_CRYPTO_FOURIER_BSK_TEST.decomp_base_log),
+            DecompositionLevelCount(CORE_CRYPTO_FOURIER_BSK_TEST.decomp_level_count),
+            noise_distribution,
+            ciphertext_modulus,
+            &mut encryption_generator_for_test(&CORE_CRYPTO_FOURIER_BSK_TEST.test_filename),
+        );
+        let mut fourier_bsk = FourierLweBootstrapKey::new(
+            bsk_for_fourier.input_lwe_dimension(),
+            bsk_for_fourier.glwe_size(),
+            bsk_for_fourier.polynomial_size(),
+            bsk_for_fourier.decomposition_base_log(),
+            bsk_for_fourier.decomposition_level_count(),
+        );
+        convert_standard_lwe_bootstrap_key_to_fourier(&bsk_for_fourier, &mut fourier_bsk);
+
+        let multi_bit_bsk = allocate_and_generate_new_lwe_multi_bit_bootstrap_key(
+            &small_lwe_secret_key,
+            &glwe_secret_key,
+            DecompositionBaseLog(CORE_CRYPTO_MULTI_BIT_BSK_TEST.decomp_base_log),
+            DecompositionLevelCount(CORE_CRYPTO_MULTI_BIT_BSK_TEST.decomp_level_count),
+            LweBskGroupingFactor(CORE_CRYPTO_MULTI_BIT_BSK_TEST.grouping_factor),
+            noise_distribution,
+            ciphertext_modulus,
+            &mut encryption_generator_for_test(&CORE_CRYPTO_MULTI_BIT_BSK_TEST.test_filename),
+        );
+
+        let pksk = allocate_and_generate_new_lwe_packing_keyswitch_key(
+            &small_lwe_secret_key,
+            &glwe_secret_key,
+            DecompositionBaseLog(CORE_CRYPTO_PKSK_TEST.decomp_base_log),
+            DecompositionLevelCount(CORE_CRYPTO_PKSK_TEST.decomp_level_count),
+            noise_distribution,
+            ciphertext_modulus,
+            &mut encryption_generator_for_test(&CORE_CRYPTO_PKSK_TEST.test_filename),
+        );
+
+        let compact_pk = allocate_and_generate_new_lwe_compact_public_key(
+            &lwe_secret_key,
+            noise_distribution,
+            ciphertext_modulus,
+            &mut encryption_generator_for_test(&CORE_CRYPTO_COMPACT_PK_TEST.test_filename),
+        );
+
+        store_versioned_test!(&ksk, &dir, &CORE_CRYPTO_KSK_TEST.test_filename);
+        store_versioned_test!(&bsk, &dir, &CORE_CRYPTO_BSK_TEST.test_filename);
+        store_versioned_test!(
+            &fourier_bsk,
+            &dir,
+            &CORE_CRYPTO_FOURIER_BSK_TEST.test_filename
+        );
+        store_versioned_test!(
+            &multi_bit_bsk,
+            &dir,
+            &CORE_CRYPTO_MULTI_BIT_BSK_TEST.test_filename
+        );
+        store_versioned_test!(&pksk, &dir, &CORE_CRYPTO_PKSK_TEST.test_filename);
+        store_versioned_test!(
+            &compact_pk,
+            &dir,
+            &CORE_CRYPTO_COMPACT_PK_TEST.test_filename
+        );
+
+        // generate ciphertexts
+        let encode = |clear_values: &[u64], delta: u64| {
+            clear_values
+                .iter()
+                .map(|value| value * delta)
+                .collect::<Vec<_>>()
+        };
+
+        let lwe_ct = allocate_and_encrypt_new_lwe_ciphertext(
+            &lwe_secret_key,
+            Plaintext(CORE_CRYPTO_LWE_CT_TEST.clear_value * CORE_CRYPTO_LWE_CT_TEST.delta),
+            noise_distribution,
+            ciphertext_modulus,
+            &mut encryption_generator_for_test(&CORE_CRYPTO_LWE_CT_TEST.test_filename),
+        );
+
+        let mut lwe_ct_list = LweCiphertextList::new(
+            0u64,
+            lwe_dimension.to_lwe_size(),
+            LweCiphertextCount(CORE_CRYPTO_LWE_CT_LIST_TEST.clear_values.len()),
+            ciphertext_modulus,
+        );
+        encrypt_lwe_ciphertext_list(
+            &lwe_secret_key,
+            &mut lwe_ct_list,
+            &PlaintextList::from_container(encode(
+                &CORE_CRYPTO_LWE_CT_LIST_TEST.clear_values,
+                CORE_CRYPTO_LWE_CT_LIST_TEST.delta,
+            )),
+            noise_distribution,
+            &mut encryption_generator_for_test(&CORE_CRYPTO_LWE_CT_LIST_TEST.test_filename),
+        );
+
+        // The compact public key is only used to encrypt the list, it is not stored
+        let mut seeder = seeder_for_test(&CORE_CRYPTO_LWE_COMPACT_CT_LIST_TEST.test_filename);
+        let mut compact_list_secret_generator =
+            SecretRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed());
+        let mut compact_list_encryption_generator =
+            EncryptionRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed(), &mut seeder);
+        let compact_public_key = allocate_and_generate_new_lwe_compact_public_key(
+            &lwe_secret_key,
+            noise_distribution,
+            ciphertext_modulus,
+            &mut compact_list_encryption_generator,
+        );
+        let mut lwe_compact_ct_list = LweCompactCiphertextList::new(
+            0u64,
+            lwe_dimension.to_lwe_size(),
+            LweCiphertextCount(CORE_CRYPTO_LWE_COMPACT_CT_LIST_TEST.clear_values.len()),
+            ciphertext_modulus,
+        );
+        encrypt_lwe_compact_ciphertext_list_with_compact_public_key(
+            &compact_public_key,
+            &mut lwe_compact_ct_list,
+            &PlaintextList::from_container(encode(
+                &CORE_CRYPTO_LWE_COMPACT_CT_LIST_TEST.clear_values,
+                CORE_CRYPTO_LWE_COMPACT_CT_LIST_TEST.delta,
+            )),
+            noise_distribution,
+            noise_distribution,
+            &mut compact_list_secret_generator,
+            &mut compact_list_encryption_generator,
+        );
+
+        let mut glwe_plaintexts = encode(
+            &CORE_CRYPTO_GLWE_CT_TEST.clear_values,
+            CORE_CRYPTO_GLWE_CT_TEST.delta,
+        );
+        glwe_plaintexts.resize(polynomial_size.0, 0);
+        let mut glwe_ct = GlweCiphertext::new(
+            0u64,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            ciphertext_modulus,
+        );
+        encrypt_glwe_ciphertext(
+            &glwe_secret_key,
+            &mut glwe_ct,
+            &PlaintextList::from_container(glwe_plaintexts.clone()),
+            noise_distribution,
+            &mut encryption_generator_for_test(&CORE_CRYPTO_GLWE_CT_TEST.test_filename),
+        );
+
+        let mut ggsw_ct = GgswCiphertext::new(
+            0u64,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            DecompositionBaseLog(CORE_CRYPTO_GGSW_CT_TEST.decomp_base_log),
+            DecompositionLevelCount(CORE_CRYPTO_GGSW_CT_TEST.decomp_level_count),
+            ciphertext_modulus,
+        );
+        encrypt_constant_ggsw_ciphertext(
+            &glwe_secret_key,
+            &mut ggsw_ct,
+            Cleartext(CORE_CRYPTO_GGSW_CT_TEST.clear_value),
+            noise_distribution,
+            &mut encryption_generator_for_test(&CORE_CRYPTO_GGSW_CT_TEST.test_filename),
+        );
+
+        // Serialize them
+        store_versioned_test!(&lwe_ct, &dir, &CORE_CRYPTO_LWE_CT_TEST.test_filename);
+        store_versioned_test!(
+            &lwe_ct_list,
+            &dir,
+            &CORE_CRYPTO_LWE_CT_LIST_TEST.test_filename
+        );
+        store_versioned_test!(
+            &lwe_compact_ct_list,
+            &dir,
+            &CORE_CRYPTO_LWE_COMPACT_CT_LIST_TEST.test_filename
+        );
+        store_versioned_test!(&glwe_ct, &dir, &CORE_CRYPTO_GLWE_CT_TEST.test_filename);
+        store_versioned_test!(&ggsw_ct, &dir, &CORE_CRYPTO_GGSW_CT_TEST.test_filename);
+
+        // generate the seeded entities
+        let seeded_test =
+            |test_filename: &str, entity: CoreCryptoSeededEntity, decompressed: &[u64]| {
+                TestMetadata::CoreCryptoSeededEntity(CoreCryptoSeededEntityTest {
+                    test_filename: Cow::Owned(test_filename.to_string()),
+                    entity,
+                    decompressed_digest: Cow::Owned(container_digest(decompressed)),
+                })
+            };
+
+        let seeded_lwe_ct = allocate_and_encrypt_new_seeded_lwe_ciphertext(
+            &lwe_secret_key,
+            Plaintext(CORE_CRYPTO_LWE_CT_TEST.clear_value * CORE_CRYPTO_LWE_CT_TEST.delta),
+            noise_distribution,
+            ciphertext_modulus,
+            &mut seeder_for_test(CORE_CRYPTO_SEEDED_LWE_CT_FILENAME),
+        );
+        store_versioned_test!(&seeded_lwe_ct, &dir, CORE_CRYPTO_SEEDED_LWE_CT_FILENAME);
+        let seeded_lwe_ct_test = seeded_test(
+            CORE_CRYPTO_SEEDED_LWE_CT_FILENAME,
+            CoreCryptoSeededEntity::SeededLweCiphertext,
+            seeded_lwe_ct.decompress_into_lwe_ciphertext().as_ref(),
+        );
+
+        let mut seeded_glwe_ct_seeder = seeder_for_test(CORE_CRYPTO_SEEDED_GLWE_CT_FILENAME);
+        let mut seeded_glwe_ct = SeededGlweCiphertext::new(
+            0u64,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            seeded_glwe_ct_seeder.seed().into(),
+            ciphertext_modulus,
+        );
+        encrypt_seeded_glwe_ciphertext(
+            &glwe_secret_key,
+            &mut seeded_glwe_ct,
+            &PlaintextList::from_container(glwe_plaintexts),
+            noise_distribution,
+            &mut seeded_glwe_ct_seeder,
+        );
+        store_versioned_test!(&seeded_glwe_ct, &dir, CORE_CRYPTO_SEEDED_GLWE_CT_FILENAME);
+        let seeded_glwe_ct_test = seeded_test(
+            CORE_CRYPTO_SEEDED_GLWE_CT_FILENAME,
+            CoreCryptoSeededEntity::SeededGlweCiphertext,
+            seeded_glwe_ct.decompress_into_glwe_ciphertext().as_ref(),
+        );
+
+        let seeded_ksk = allocate_and_generate_new_seeded_lwe_keyswitch_key(
+            &lwe_secret_key,
+            &small_lwe_secret_key,
+            DecompositionBaseLog(CORE_CRYPTO_KSK_TEST.decomp_base_log),
+            DecompositionLevelCount(CORE_CRYPTO_KSK_TEST.decomp_level_count),
+            noise_distribution,
+            ciphertext_modulus,
+            &mut seeder_for_test(CORE_CRYPTO_SEEDED_KSK_FILENAME),
+        );
+        store_versioned_test!(&seeded_ksk, &dir, CORE_CRYPTO_SEEDED_KSK_FILENAME);
+        let seeded_ksk_test = seeded_test(
+            CORE_CRYPTO_SEEDED_KSK_FILENAME,
+            CoreCryptoSeededEntity::SeededLweKeyswitchKey,
+            seeded_ksk.decompress_into_lwe_keyswitch_key().as_ref(),
+        );
+
+        let seeded_bsk = allocate_and_generate_new_seeded_lwe_bootstrap_key(
+            &small_lwe_secret_key,
+            &glwe_secret_key,
+            DecompositionBaseLog(CORE_CRYPTO_BSK_TEST.decomp_base_log),
+            DecompositionLevelCount(CORE_CRYPTO_BSK_TEST.decomp_level_count),
+            noise_distribution,
+            ciphertext_modulus,
+            &mut seeder_for_test(CORE_CRYPTO_SEEDED_BSK_FILENAME),
+        );
+        store_versioned_test!(&seeded_bsk, &dir, CORE_CRYPTO_SEEDED_BSK_FILENAME);
+        let seeded_bsk_test = seeded_test(
+            CORE_CRYPTO_SEEDED_BSK_FILENAME,
+            CoreCryptoSeededEntity::SeededLweBootstrapKey,
+            seeded_bsk.decompress_into_lwe_bootstrap_key().as_ref(),
+        );
+
+        let seeded_compact_pk = allocate_and_generate_new_seeded_lwe_compact_public_key(
+            &lwe_secret_key,
+            noise_distribution,
+            ciphertext_modulus,
+            &mut seeder_for_test(CORE_CRYPTO_SEEDED_COMPACT_PK_FILENAME),
+        );
+        store_versioned_test!(
+            &seeded_compact_pk,
+            &dir,
+            CORE_CRYPTO_SEEDED_COMPACT_PK_FILENAME
+        );
+        let seeded_compact_pk_test = seeded_test(
+            CORE_CRYPTO_SEEDED_COMPACT_PK_FILENAME,
+            CoreCryptoSeededEntity::SeededLweCompactPublicKey,
+            seeded_compact_pk
+                .decompress_into_lwe_compact_public_key()
+                .as_ref(),
+        );
+
+        vec![
+            TestMetadata::CoreCryptoLweSecretKey(CORE_CRYPTO_LWE_SECRET_KEY_TEST),
+            TestMetadata::CoreCryptoLweSecretKey(CORE_CRYPTO_SMALL_LWE_SECRET_KEY_TEST),
+            TestMetadata::CoreCryptoGlweSecretKey(CORE_CRYPTO_GLWE_SECRET_KEY_TEST),
+            TestMetadata::CoreCryptoLweKeyswitchKey(CORE_CRYPTO_KSK_TEST),
+            TestMetadata::CoreCryptoLweBootstrapKey(CORE_CRYPTO_BSK_TEST),
+            TestMetadata::CoreCryptoLweBootstrapKey(CORE_CRYPTO_FOURIER_BSK_TEST),
+            TestMetadata::CoreCryptoLweMultiBitBootstrapKey(CORE_CRYPTO_MULTI_BIT_BSK_TEST),
+            TestMetadata::CoreCryptoLwePackingKeyswitchKey(CORE_CRYPTO_PKSK_TEST),
+            TestMetadata::CoreCryptoLweCompactPublicKey(CORE_CRYPTO_COMPACT_PK_TEST),
+            TestMetadata::CoreCryptoLweCiphertext(CORE_CRYPTO_LWE_CT_TEST),
+            TestMetadata::CoreCryptoLweCiphertextList(CORE_CRYPTO_LWE_CT_LIST_TEST),
+            TestMetadata::CoreCryptoLweCompactCiphertextList(CORE_CRYPTO_LWE_COMPACT_CT_LIST_TEST),
+            TestMetadata::CoreCryptoGlweCiphertext(CORE_CRYPTO_GLWE_CT_TEST),
+            TestMetadata::CoreCryptoGgswCiphertext(CORE_CRYPTO_GGSW_CT_TEST),
+            seeded_lwe_ct_test,
+            seeded_glwe_ct_test,
+            seeded_ksk_test,
+            seeded_bsk_test,
+            seeded_compact_pk_test,
+        ]
+    }
+
+    fn gen_boolean_data(base_data_dir: &Path) -> Vec<TestMetadata> {
+        let dir = Self::data_dir(base_data_dir).join(BOOLEAN_MODULE_NAME);
+        create_dir_all(&dir).unwrap();
+
+        let seed_test =
+            |test_filename: &str| Self::seed_prng_for_test(BOOLEAN_MODULE_NAME, test_filename);
+
+        // generate keys
+        seed_test(&BOOLEAN_CLIENTKEY_TEST.test_filename);
+        let boolean_client_key =
+            boolean::client_key::ClientKey::new(&BOOLEAN_CLIENTKEY_TEST.parameters.into());
+        seed_test(&BOOLEAN_SERVERKEY_TEST.test_filename);
+        let boolean_server_key = boolean::server_key::ServerKey::new(&boolean_client_key);
+        seed_test(&BOOLEAN_COMPRESSED_SERVERKEY_TEST.test_filename);
+        let boolean_compressed_server_key =
+            boolean::server_key::CompressedServerKey::new(&boolean_client_key);
+        seed_test(&BOOLEAN_PUBKEY_TEST.test_filename);
+        let boolean_public_key = boolean::public_key::PublicKey::new(&boolean_client_key);
+        seed_test(&BOOLEAN_COMPRESSED_PUBKEY_TEST.test_filename);
+        let boolean_compressed_public_key =
+            boolean::public_key::CompressedPublicKey::new(&boolean_client_key);
+
+        store_versioned_test!(
+            &boolean_client_key,
+            &dir,
+            &BOOLEAN_CLIENTKEY_TEST.test_filename,
+        );
+        store_versioned_test!(
+            &boolean_server_key,
+            &dir,
+            &BOOLEAN_SERVERKEY_TEST.test_filename,
+        );
+        store_versioned_test!(
+            &boolean_compressed_server_key,
+            &dir,
+            &BOOLEAN_COMPRESSED_SERVERKEY_TEST.test_filename,
+        );
+        store_versioned_test!(
+            &boolean_public_key,
+            &dir,
+            &BOOLEAN_PUBKEY_TEST.test_filename,
+        );
+        store_versioned_test!(
+            &boolean_compressed_public_key,
+            &dir,
+            &BOOLEAN_COMPRESSED_PUBKEY_TEST.test_filename,
+        );
+
+        // generate ciphertexts
+        seed_test(&BOOLEAN_CT_TEST.test_filename);
+        let ct = boolean_client_key.encrypt(BOOLEAN_CT_TEST.clear_value);
+        seed_test(&BOOLEAN_COMPRESSED_CT_TEST.test_filename);
+        let compressed_ct =
+            boolean_client_key.encrypt_compressed(BOOLEAN_COMPRESSED_CT_TEST.clear_value);
+
+        // Serialize them
+        store_versioned_test!(&ct, &dir, &BOOLEAN_CT_TEST.test_filename);
+        store_versioned_test!(
+            &compressed_ct,
+            &dir,
+            &BOOLEAN_COMPRESSED_CT_TEST.test_filename
+        );
+
+        vec![
+            TestMetadata::BooleanClientKey(BOOLEAN_CLIENTKEY_TEST),
+            TestMetadata::BooleanServerKey(BOOLEAN_SERVERKEY_TEST),
+            TestMetadata::BooleanServerKey(BOOLEAN_COMPRESSED_SERVERKEY_TEST),
+            TestMetadata::BooleanPublicKey(BOOLEAN_PUBKEY_TEST),
+            TestMetadata::BooleanPublicKey(BOOLEAN_COMPRESSED_PUBKEY_TEST),
+            TestMetadata::BooleanCiphertext(BOOLEAN_CT_TEST),
+            TestMetadata::BooleanCiphertext(BOOLEAN_COMPRESSED_CT_TEST),
         ]
     }
 }
@@ -1,31 +1,72 @@
 use crate::generate::{
-    store_versioned_test_tfhe_1_1, TfhersVersion,
+    store_versioned_auxiliary_tfhe_1_1, store_versioned_test_tfhe_1_1, test_seed, ModuleGenerator,
+    TfhersVersion, INSECURE_SMALL_BOOLEAN_TEST_PARAMS, INSECURE_SMALL_PK_TEST_PARAMS,
     INSECURE_SMALL_TEST_NOISE_SQUASHING_PARAMS_MS_NOISE_REDUCTION,
-    INSECURE_SMALL_TEST_PARAMS_MS_NOISE_REDUCTION,
+    INSECURE_SMALL_TEST_PARAMS_MS_NOISE_REDUCTION, VALID_TEST_PARAMS_TUNIFORM_COMPRESSION,
 };
 use crate::{
-    HlClientKeyTest, HlServerKeyTest, HlSquashedNoiseBoolCiphertextTest,
-    HlSquashedNoiseSignedCiphertextTest, HlSquashedNoiseUnsignedCiphertextTest, TestDistribution,
+    container_digest, BooleanCiphertextTest, BooleanClientKeyTest, BooleanPublicKeyTest,
+    BooleanServerKeyTest, CoreCryptoGgswCiphertextTest, CoreCryptoGlweCiphertextTest,
+    CoreCryptoGlweSecretKeyTest, CoreCryptoLweBootstrapKeyTest, CoreCryptoLweCiphertextListTest,
+    CoreCryptoLweCiphertextTest, CoreCryptoLweCompactCiphertextListTest,
+    CoreCryptoLweCompactPublicKeyTest, CoreCryptoLweKeyswitchKeyTest,
+    CoreCryptoLweMultiBitBootstrapKeyTest, CoreCryptoLwePackingKeyswitchKeyTest,
+    CoreCryptoLweSecretKeyTest, CoreCryptoSeededEntity, CoreCryptoSeededEntityTest,
+    HlClientKeyTest, HlServerKeyTest, HlSignedWideCiphertextTest,
+    HlSquashedNoiseBoolCiphertextTest, HlSquashedNoiseSignedCiphertextTest,
+    HlSquashedNoiseUnsignedCiphertextTest, HlTypedCiphertextListTest, HlWideCiphertextTest,
+    IntegerBooleanBlockTest, IntegerClientKeyTest, IntegerCrtCiphertextTest,
+    IntegerRadixCiphertextTest, IntegerSignedRadixCiphertextTest, ShortintCiphertextTest,
+    ShortintClientKeyTest, ShortintCompactCiphertextListTest, ShortintCompressedCiphertextTest,
+    ShortintKeySwitchingKeyTest, ShortintPublicKeyTest, ShortintServerKeyTest,
+    TestBooleanParameterSet, TestClearValue, TestCompressionParameterSet, TestDistribution,
     TestMetadata, TestModulusSwitchNoiseReductionParams, TestNoiseSquashingParams,
-    TestParameterSet, HL_MODULE_NAME,
+    TestParameterSet, TestWideInteger, BOOLEAN_MODULE_NAME, CORE_CRYPTO_MODULE_NAME,
+    HL_MODULE_NAME, INTEGER_MODULE_NAME, SHORTINT_MODULE_NAME,
 };
 use std::borrow::Cow;
 use std::fs::create_dir_all;
+use std::path::Path;
+use tfhe_1_1::boolean;
 use tfhe_1_1::boolean::engine::BooleanEngine;
+use tfhe_1_1::boolean::parameters::BooleanParameters;
 use tfhe_1_1::core_crypto::commons::generators::DeterministicSeeder;
 use tfhe_1_1::core_crypto::commons::math::random::DefaultRandomGenerator;
 use tfhe_1_1::core_crypto::prelude::{
-    LweCiphertextCount, NoiseEstimationMeasureBound, RSigmaFactor, UnsignedInteger, Variance,
+    allocate_and_encrypt_new_lwe_ciphertext, allocate_and_encrypt_new_seeded_lwe_ciphertext,
+    allocate_and_generate_new_binary_glwe_secret_key,
+    allocate_and_generate_new_binary_lwe_secret_key, allocate_and_generate_new_lwe_bootstrap_key,
+    allocate_and_generate_new_lwe_compact_public_key, allocate_and_generate_new_lwe_keyswitch_key,
+    allocate_and_generate_new_lwe_multi_bit_bootstrap_key,
+    allocate_and_generate_new_lwe_packing_keyswitch_key,
+    allocate_and_generate_new_seeded_lwe_bootstrap_key,
+    allocate_and_generate_new_seeded_lwe_compact_public_key,
+    allocate_and_generate_new_seeded_lwe_keyswitch_key,
+    convert_standard_lwe_bootstrap_key_to_fourier, encrypt_constant_ggsw_ciphertext,
+    encrypt_glwe_ciphertext, encrypt_lwe_ciphertext_list,
+    encrypt_lwe_compact_ciphertext_list_with_compact_public_key, encrypt_seeded_glwe_ciphertext,
+    CiphertextModulusLog, Cleartext, EncryptionRandomGenerator, FourierLweBootstrapKey,
+    GgswCiphertext, GlweCiphertext, LweBskGroupingFactor, LweCiphertextCount, LweCiphertextList,
+    LweCompactCiphertextList, NoiseEstimationMeasureBound, Plaintext, PlaintextList, RSigmaFactor,
+    SecretRandomGenerator, SeededGlweCiphertext, Seeder, UnsignedInteger, Variance,
 };
+use tfhe_1_1::integer;
+use tfhe_1_1::integer::{I256, U256};
 use tfhe_1_1::prelude::*;
+use tfhe_1_1::shortint;
 use tfhe_1_1::shortint::engine::ShortintEngine;
 use tfhe_1_1::shortint::parameters::{
-    CarryModulus, CiphertextModulus, ClassicPBSParameters, CoreCiphertextModulus,
-    DecompositionBaseLog, DecompositionLevelCount, DynamicDistribution, EncryptionKeyChoice,
-    GlweDimension, LweDimension, MaxNoiseLevel, MessageModulus, ModulusSwitchNoiseReductionParams,
-    NoiseSquashingParameters, PBSParameters, PolynomialSize, StandardDev,
+    CarryModulus, CiphertextModulus, ClassicPBSParameters, CompressionParameters,
+    CoreCiphertextModulus, DecompositionBaseLog, DecompositionLevelCount, DynamicDistribution,
+    EncryptionKeyChoice, GlweDimension, LweDimension, MaxNoiseLevel, MessageModulus,
+    ModulusSwitchNoiseReductionParams, NoiseSquashingParameters, PBSParameters, PolynomialSize,
+    ShortintKeySwitchingParameters, StandardDev,
+};
+use tfhe_1_1::{
+    set_server_key, ClientKey, CompressedCiphertextListBuilder, CompressedServerKey,
+    FheAsciiString, FheBool, FheInt128, FheInt16, FheInt160, FheInt256, FheInt64, FheUint128,
+    FheUint16, FheUint160, FheUint256, FheUint32, FheUint4, FheUint64, FheUint8, Seed, ServerKey,
 };
-use tfhe_1_1::{set_server_key, CompressedServerKey, FheBool, FheInt64, FheUint64, Seed};
 
 macro_rules! store_versioned_test {
     ($msg:expr, $dir:expr, $test_filename:expr $(,)? ) => {
@@ -33,6 +74,12 @@ macro_rules! store_versioned_test {
     };
 }
 
+macro_rules! store_versioned_auxiliary {
+    ($msg:expr, $dir:expr, $test_filename:expr $(,)? ) => {
+        store_versioned_auxiliary_tfhe_1_1($msg, $dir, $test_filename)
+    };
+}
+
 impl<Scalar: UnsignedInteger> From<TestDistribution> for DynamicDistribution<Scalar> {
     fn from(value: TestDistribution) -> Self {
         match value {
@@ -46,6 +93,29 @@ impl<Scalar: UnsignedInteger> From<TestDistribution> for DynamicDistribution<Sca
     }
 }
 
+impl From<TestBooleanParameterSet> for BooleanParameters {
+    fn from(value: TestBooleanParameterSet) -> Self {
+        BooleanParameters {
+            lwe_dimension: LweDimension(value.lwe_dimension),
+            glwe_dimension: GlweDimension(value.glwe_dimension),
+            polynomial_size: PolynomialSize(value.polynomial_size),
+            lwe_noise_distribution: value.lwe_noise_distribution.into(),
+            glwe_noise_distribution: value.glwe_noise_distribution.into(),
+            pbs_base_log: DecompositionBaseLog(value.pbs_base_log),
+            pbs_level: DecompositionLevelCount(value.pbs_level),
+            ks_base_log: DecompositionBaseLog(value.ks_base_log),
+            ks_level: DecompositionLevelCount(value.ks_level),
+            encryption_key_choice: {
+                match &*value.encryption_key_choice {
+                    "big" => EncryptionKeyChoice::Big,
+                    "small" => EncryptionKeyChoice::Small,
+                    _ => panic!("Invalid encryption key choice"),
+                }
+            },
+        }
+    }
+}
+
 impl From<TestModulusSwitchNoiseReductionParams> for ModulusSwitchNoiseReductionParams {
     fn from(value: TestModulusSwitchNoiseReductionParams) -> Self {
         let TestModulusSwitchNoiseReductionParams {
@@ -97,6 +167,22 @@ impl From<TestParameterSet> for ClassicPBSParameters {
     }
 }
 
+impl From<&ShortintKeySwitchingKeyTest> for ShortintKeySwitchingParameters {
+    fn from(value: &ShortintKeySwitchingKeyTest) -> Self {
+        ShortintKeySwitchingParameters {
+            ks_base_log: DecompositionBaseLog(value.ks_base_log),
+            ks_level: DecompositionLevelCount(value.ks_level),
+            destination_key: {
+                match &*value.destination_key {
+                    "big" => EncryptionKeyChoice::Big,
+                    "small" => EncryptionKeyChoice::Small,
+                    _ => panic!("Invalid encryption key choice"),
+                }
+            },
+        }
+    }
+}
+
 impl From<TestParameterSet> for PBSParameters {
     fn from(value: TestParameterSet) -> Self {
         let tmp: ClassicPBSParameters = value.into();
@@ -104,6 +190,33 @@ impl From<TestParameterSet> for PBSParameters {
     }
 }
 
+impl From<TestCompressionParameterSet> for CompressionParameters {
+    fn from(value: TestCompressionParameterSet) -> Self {
+        let TestCompressionParameterSet {
+            br_level,
+            br_base_log,
+            packing_ks_level,
+            packing_ks_base_log,
+            packing_ks_polynomial_size,
+            packing_ks_glwe_dimension,
+            lwe_per_glwe,
+            storage_log_modulus,
+            packing_ks_key_noise_distribution,
+        } = value;
+        Self {
+            br_level: DecompositionLevelCount(br_level),
+            br_base_log: DecompositionBaseLog(br_base_log),
+            packing_ks_level: DecompositionLevelCount(packing_ks_level),
+            packing_ks_base_log: DecompositionBaseLog(packing_ks_base_log),
+            packing_ks_polynomial_size: PolynomialSize(packing_ks_polynomial_size),
+            packing_ks_glwe_dimension: GlweDimension(packing_ks_glwe_dimension),
+            lwe_per_glwe: LweCiphertextCount(lwe_per_glwe),
+            storage_log_modulus: CiphertextModulusLog(storage_log_modulus),
+            packing_ks_key_noise_distribution: packing_ks_key_noise_distribution.into(),
+        }
+    }
+}
+
 impl From<TestNoiseSquashingParams> for NoiseSquashingParameters {
     fn from(value: TestNoiseSquashingParams) -> Self {
         let TestNoiseSquashingParams {
@@ -182,10 +295,542 @@ const HL_SQUASHED_NOISE_BOOL_TRUE_CIPHERTEXT_TEST: HlSquashedNoiseBoolCiphertext
         clear_value: true,
     };
 
+const HL_CLIENTKEY_WITH_COMPRESSION_TEST: HlClientKeyTest = HlClientKeyTest {
+    test_filename: Cow::Borrowed("client_key_with_compression"),
+    parameters: INSECURE_SMALL_TEST_PARAMS_MS_NOISE_REDUCTION,
+};
+
+const HL_COMPRESSED_LIST_WITH_STRINGS_TEST: HlTypedCiphertextListTest = HlTypedCiphertextListTest {
+    test_filename: Cow::Borrowed("hl_compressed_heterogeneous_list_with_strings"),
+    key_filename: HL_CLIENTKEY_WITH_COMPRESSION_TEST.test_filename,
+    clear_values: Cow::Borrowed(&[
+        TestClearValue::Unsigned {
+            num_bits: 8,
+            value: TestWideInteger::from_u64(17),
+        },
+        TestClearValue::String(Cow::Borrowed("tfhe-rs")),
+        TestClearValue::Signed {
+            num_bits: 16,
+            value: TestWideInteger::from_i64(-1234),
+        },
+        TestClearValue::String(Cow::Borrowed("Zama")),
+        TestClearValue::Bool(true),
+    ]),
+    compressed: true,
+    proof_info: None,
+};
+
+const HL_WIDE_INTEGERS_CLIENT_KEY_FILENAME: &str = "client_key_wide_integers";
+
+const HL_UINT4_CT_TEST: HlWideCiphertextTest = HlWideCiphertextTest {
+    test_filename: Cow::Borrowed("ct_uint4"),
+    key_filename: Cow::Borrowed(HL_WIDE_INTEGERS_CLIENT_KEY_FILENAME),
+    compressed: false,
+    num_bits: 4,
+    clear_value: TestWideInteger::from_u64(13),
+};
+
+const HL_UINT16_CT_TEST: HlWideCiphertextTest = HlWideCiphertextTest {
+    test_filename: Cow::Borrowed("ct_uint16"),
+    key_filename: Cow::Borrowed(HL_WIDE_INTEGERS_CLIENT_KEY_FILENAME),
+    compressed: false,
+    num_bits: 16,
+    clear_value: TestWideInteger::from_u64(48879),
+};
+
+const HL_UINT32_CT_TEST: HlWideCiphertextTest = HlWideCiphertextTest {
+    test_filename: Cow::Borrowed("ct_uint32"),
+    key_filename: Cow::Borrowed(HL_WIDE_INTEGERS_CLIENT_KEY_FILENAME),
+    compressed: false,
+    num_bits: 32,
+    clear_value: TestWideInteger::from_u64(3141592653),
+};
+
+const HL_UINT64_CT_TEST: HlWideCiphertextTest = HlWideCiphertextTest {
+    test_filename: Cow::Borrowed("ct_uint64"),
+    key_filename: Cow::Borrowed(HL_WIDE_INTEGERS_CLIENT_KEY_FILENAME),
+    compressed: false,
+    num_bits: 64,
+    clear_value: TestWideInteger::from_u64(u64::MAX - 1),
+};
+
+const HL_UINT128_CT_TEST: HlWideCiphertextTest = HlWideCiphertextTest {
+    test_filename: Cow::Borrowed("ct_uint128"),
+    key_filename: Cow::Borrowed(HL_WIDE_INTEGERS_CLIENT_KEY_FILENAME),
+    compressed: false,
+    num_bits: 128,
+    clear_value: TestWideInteger::from_u128(u128::MAX / 3),
+};
+
+const HL_UINT160_CT_TEST: HlWideCiphertextTest = HlWideCiphertextTest {
+    test_filename: Cow::Borrowed("ct_uint160"),
+    key_filename: Cow::Borrowed(HL_WIDE_INTEGERS_CLIENT_KEY_FILENAME),
+    compressed: false,
+    num_bits: 160,
+    clear_value: TestWideInteger::from_words([
+        0x0123456789abcdef,
+        0xfedcba9876543210,
+        0xdeadbeef,
+        0,
+    ]),
+};
+
+const HL_UINT256_CT_TEST: HlWideCiphertextTest = HlWideCiphertextTest {
+    test_filename: Cow::Borrowed("ct_uint256"),
+    key_filename: Cow::Borrowed(HL_WIDE_INTEGERS_CLIENT_KEY_FILENAME),
+    compressed: false,
+    num_bits: 256,
+    clear_value: TestWideInteger::from_words([u64::MAX, 0, 0xa5a5a5a5a5a5a5a5, 1 << 63]),
+};
+
+const HL_INT16_CT_TEST: HlSignedWideCiphertextTest = HlSignedWideCiphertextTest {
+    test_filename: Cow::Borrowed("ct_int16"),
+    key_filename: Cow::Borrowed(HL_WIDE_INTEGERS_CLIENT_KEY_FILENAME),
+    compressed: false,
+    num_bits: 16,
+    clear_value: TestWideInteger::from_i64(-12345),
+};
+
+const HL_INT64_CT_TEST: HlSignedWideCiphertextTest = HlSignedWideCiphertextTest {
+    test_filename: Cow::Borrowed("ct_int64"),
+    key_filename: Cow::Borrowed(HL_WIDE_INTEGERS_CLIENT_KEY_FILENAME),
+    compressed: false,
+    num_bits: 64,
+    clear_value: TestWideInteger::from_i64(i64::MIN + 1),
+};
+
+const HL_INT128_CT_TEST: HlSignedWideCiphertextTest = HlSignedWideCiphertextTest {
+    test_filename: Cow::Borrowed("ct_int128"),
+    key_filename: Cow::Borrowed(HL_WIDE_INTEGERS_CLIENT_KEY_FILENAME),
+    compressed: false,
+    num_bits: 128,
+    clear_value: TestWideInteger::from_i128(i128::MIN / 3),
+};
+
+const HL_INT160_CT_TEST: HlSignedWideCiphertextTest = HlSignedWideCiphertextTest {
+    test_filename: Cow::Borrowed("ct_int160"),
+    key_filename: Cow::Borrowed(HL_WIDE_INTEGERS_CLIENT_KEY_FILENAME),
+    compressed: false,
+    num_bits: 160,
+    clear_value: TestWideInteger::from_words([1, 0, 0xffffffff80000000, u64::MAX]),
+};
+
+const HL_INT256_CT_TEST: HlSignedWideCiphertextTest = HlSignedWideCiphertextTest {
+    test_filename: Cow::Borrowed("ct_int256"),
+    key_filename: Cow::Borrowed(HL_WIDE_INTEGERS_CLIENT_KEY_FILENAME),
+    compressed: false,
+    num_bits: 256,
+    clear_value: TestWideInteger::from_words([0, 0, 0, 1 << 63]),
+};
+
+// Shortint
+const SHORTINT_CLIENT_KEY_FILENAME: &str = "client_key";
+const SHORTINT_SMALL_CLIENT_KEY_FILENAME: &str = "client_key_small";
+
+// WARNING: these parameters are completely insecure, they are only used to keep the server keys
+// small
+const SHORTINT_CLIENTKEY_TEST: ShortintClientKeyTest = ShortintClientKeyTest {
+    test_filename: Cow::Borrowed(SHORTINT_CLIENT_KEY_FILENAME),
+    parameters: INSECURE_SMALL_TEST_PARAMS_MS_NOISE_REDUCTION,
+};
+
+const SHORTINT_SERVERKEY_TEST: ShortintServerKeyTest = ShortintServerKeyTest {
+    test_filename: Cow::Borrowed("server_key"),
+    client_key_filename: Cow::Borrowed(SHORTINT_CLIENT_KEY_FILENAME),
+    compressed: false,
+};
+
+const SHORTINT_COMPRESSED_SERVERKEY_TEST: ShortintServerKeyTest = ShortintServerKeyTest {
+    test_filename: Cow::Borrowed("compressed_server_key"),
+    client_key_filename: Cow::Borrowed(SHORTINT_CLIENT_KEY_FILENAME),
+    compressed: true,
+};
+
+// The seeded ciphertext is compressed with the client key
+const SHORTINT_COMPRESSED_CT_TEST: ShortintCompressedCiphertextTest =
+    ShortintCompressedCiphertextTest {
+        test_filename: Cow::Borrowed("ct_compressed_seeded"),
+        key_filename: Cow::Borrowed(SHORTINT_CLIENT_KEY_FILENAME),
+        modulus_switched: false,
+        clear_value: 3,
+    };
+
+// The modulus switched ciphertext is compressed with the server key
+const SHORTINT_COMPRESSED_CT_MODSWITCHED_TEST: ShortintCompressedCiphertextTest =
+    ShortintCompressedCiphertextTest {
+        test_filename: Cow::Borrowed("ct_compressed_modswitched"),
+        key_filename: Cow::Borrowed(SHORTINT_CLIENT_KEY_FILENAME),
+        modulus_switched: true,
+        clear_value: 2,
+    };
+
+const SHORTINT_CT1_TEST: ShortintCiphertextTest = ShortintCiphertextTest {
+    test_filename: Cow::Borrowed("ct1"),
+    key_filename: Cow::Borrowed(SHORTINT_CLIENT_KEY_FILENAME),
+    clear_value: 1,
+};
+
+const SHORTINT_CT2_TEST: ShortintCiphertextTest = ShortintCiphertextTest {
+    test_filename: Cow::Borrowed("ct2"),
+    key_filename: Cow::Borrowed(SHORTINT_CLIENT_KEY_FILENAME),
+    clear_value: 3,
+};
+
+// WARNING: these parameters are completely insecure, they are only used to keep the non compact
+// public keys small
+const SHORTINT_SMALL_CLIENTKEY_TEST: ShortintClientKeyTest = ShortintClientKeyTest {
+    test_filename: Cow::Borrowed(SHORTINT_SMALL_CLIENT_KEY_FILENAME),
+    parameters: INSECURE_SMALL_PK_TEST_PARAMS,
+};
+
+const SHORTINT_PUBKEY_TEST: ShortintPublicKeyTest = ShortintPublicKeyTest {
+    test_filename: Cow::Borrowed("public_key"),
+    client_key_filename: Cow::Borrowed(SHORTINT_SMALL_CLIENT_KEY_FILENAME),
+    compressed: false,
+    compact: false,
+    clear_value: 1,
+};
+
+const SHORTINT_COMPRESSED_PUBKEY_TEST: ShortintPublicKeyTest = ShortintPublicKeyTest {
+    test_filename: Cow::Borrowed("compressed_public_key"),
+    client_key_filename: Cow::Borrowed(SHORTINT_SMALL_CLIENT_KEY_FILENAME),
+    compressed: true,
+    compact: false,
+    clear_value: 0,
+};
+
+// The compact public keys are generated with the main client key, whose encryption lwe dimension
+// is a power of two
+const SHORTINT_COMPACT_PUBKEY_TEST: ShortintPublicKeyTest = ShortintPublicKeyTest {
+    test_filename: Cow::Borrowed("compact_public_key"),
+    client_key_filename: Cow::Borrowed(SHORTINT_CLIENT_KEY_FILENAME),
+    compressed: false,
+    compact: true,
+    clear_value: 2,
+};
+
+const SHORTINT_COMPRESSED_COMPACT_PUBKEY_TEST: ShortintPublicKeyTest = ShortintPublicKeyTest {
+    test_filename: Cow::Borrowed("compressed_compact_public_key"),
+    client_key_filename: Cow::Borrowed(SHORTINT_CLIENT_KEY_FILENAME),
+    compressed: true,
+    compact: true,
+    clear_value: 3,
+};
+
+// The compact lists are encrypted with the compact public key
+const SHORTINT_COMPACT_LIST_TEST: ShortintCompactCiphertextListTest =
+    ShortintCompactCiphertextListTest {
+        test_filename: Cow::Borrowed("compact_list"),
+        key_filename: Cow::Borrowed(SHORTINT_CLIENT_KEY_FILENAME),
+        packed: false,
+        clear_values: Cow::Borrowed(&[0, 3, 1, 2]),
+    };
+
+const SHORTINT_PACKED_COMPACT_LIST_TEST: ShortintCompactCiphertextListTest =
+    ShortintCompactCiphertextListTest {
+        test_filename: Cow::Borrowed("packed_compact_list"),
+        key_filename: Cow::Borrowed(SHORTINT_CLIENT_KEY_FILENAME),
+        packed: true,
+        clear_values: Cow::Borrowed(&[7, 12, 15]),
+    };
+
+// The keyswitching keys cast from the client key to another one with the same parameters
+const SHORTINT_KSK_OUTPUT_CLIENT_KEY_FILENAME: &str = "client_key_ksk_output";
+
+const SHORTINT_KSK_OUTPUT_CLIENTKEY_TEST: ShortintClientKeyTest = ShortintClientKeyTest {
+    test_filename: Cow::Borrowed(SHORTINT_KSK_OUTPUT_CLIENT_KEY_FILENAME),
+    parameters: INSECURE_SMALL_TEST_PARAMS_MS_NOISE_REDUCTION,
+};
+
+const SHORTINT_KSK_TEST: ShortintKeySwitchingKeyTest = ShortintKeySwitchingKeyTest {
+    test_filename: Cow::Borrowed("keyswitching_key"),
+    input_key_filename: Cow::Borrowed(SHORTINT_CLIENT_KEY_FILENAME),
+    output_key_filename: Cow::Borrowed(SHORTINT_KSK_OUTPUT_CLIENT_KEY_FILENAME),
+    compressed: false,
+    ks_base_log: 5,
+    ks_level: 3,
+    destination_key: Cow::Borrowed("small"),
+    clear_value: 1,
+};
+
+const SHORTINT_COMPRESSED_KSK_TEST: ShortintKeySwitchingKeyTest = ShortintKeySwitchingKeyTest {
+    test_filename: Cow::Borrowed("compressed_keyswitching_key"),
+    input_key_filename: Cow::Borrowed(SHORTINT_CLIENT_KEY_FILENAME),
+    output_key_filename: Cow::Borrowed(SHORTINT_KSK_OUTPUT_CLIENT_KEY_FILENAME),
+    compressed: true,
+    ks_base_log: 5,
+    ks_level: 3,
+    destination_key: Cow::Borrowed("small"),
+    clear_value: 0,
+};
+
+// Integer
+const INTEGER_CLIENT_KEY_FILENAME: &str = "client_key";
+
+const INTEGER_CLIENTKEY_TEST: IntegerClientKeyTest = IntegerClientKeyTest {
+    test_filename: Cow::Borrowed(INTEGER_CLIENT_KEY_FILENAME),
+    parameters: INSECURE_SMALL_TEST_PARAMS_MS_NOISE_REDUCTION,
+};
+
+const INTEGER_RADIX_CT_TEST: IntegerRadixCiphertextTest = IntegerRadixCiphertextTest {
+    test_filename: Cow::Borrowed("radix_ciphertext"),
+    key_filename: Cow::Borrowed(INTEGER_CLIENT_KEY_FILENAME),
+    num_blocks: 4,
+    clear_value: 187,
+};
+
+const INTEGER_SIGNED_RADIX_CT_TEST: IntegerSignedRadixCiphertextTest =
+    IntegerSignedRadixCiphertextTest {
+        test_filename: Cow::Borrowed("signed_radix_ciphertext"),
+        key_filename: Cow::Borrowed(INTEGER_CLIENT_KEY_FILENAME),
+        num_blocks: 4,
+        clear_value: -37,
+    };
+
+const INTEGER_CRT_CT_TEST: IntegerCrtCiphertextTest = IntegerCrtCiphertextTest {
+    test_filename: Cow::Borrowed("crt_ciphertext"),
+    key_filename: Cow::Borrowed(INTEGER_CLIENT_KEY_FILENAME),
+    crt_bases: Cow::Borrowed(&[2, 3, 5]),
+    clear_value: 23,
+};
+
+const INTEGER_BOOLEAN_BLOCK_TEST: IntegerBooleanBlockTest = IntegerBooleanBlockTest {
+    test_filename: Cow::Borrowed("boolean_block"),
+    key_filename: Cow::Borrowed(INTEGER_CLIENT_KEY_FILENAME),
+    clear_value: true,
+};
+
+// Core crypto
+const CORE_CRYPTO_LWE_SECRET_KEY_FILENAME: &str = "lwe_secret_key";
+const CORE_CRYPTO_SMALL_LWE_SECRET_KEY_FILENAME: &str = "small_lwe_secret_key";
+const CORE_CRYPTO_GLWE_SECRET_KEY_FILENAME: &str = "glwe_secret_key";
+
+// The compact public key needs a power of two lwe dimension
+const CORE_CRYPTO_LWE_DIMENSION: usize = 512;
+// WARNING: this dimension is completely insecure, it is only used to keep the bootstrapping and
+// packing keys small
+const CORE_CRYPTO_SMALL_LWE_DIMENSION: usize = 16;
+const CORE_CRYPTO_GLWE_DIMENSION: usize = 1;
+const CORE_CRYPTO_POLYNOMIAL_SIZE: usize = 256;
+const CORE_CRYPTO_CIPHERTEXT_MODULUS: u128 = 1 << 64;
+const CORE_CRYPTO_NOISE_DISTRIBUTION: TestDistribution =
+    TestDistribution::TUniform { bound_log2: 20 };
+// 4 bits messages with a padding bit
+const CORE_CRYPTO_DELTA: u64 = 1 << 59;
+
+const CORE_CRYPTO_LWE_SECRET_KEY_TEST: CoreCryptoLweSecretKeyTest = CoreCryptoLweSecretKeyTest {
+    test_filename: Cow::Borrowed(CORE_CRYPTO_LWE_SECRET_KEY_FILENAME),
+    lwe_dimension: CORE_CRYPTO_LWE_DIMENSION,
+};
+
+const CORE_CRYPTO_SMALL_LWE_SECRET_KEY_TEST: CoreCryptoLweSecretKeyTest =
+    CoreCryptoLweSecretKeyTest {
+        test_filename: Cow::Borrowed(CORE_CRYPTO_SMALL_LWE_SECRET_KEY_FILENAME),
+        lwe_dimension: CORE_CRYPTO_SMALL_LWE_DIMENSION,
+    };
+
+const CORE_CRYPTO_GLWE_SECRET_KEY_TEST: CoreCryptoGlweSecretKeyTest = CoreCryptoGlweSecretKeyTest {
+    test_filename: Cow::Borrowed(CORE_CRYPTO_GLWE_SECRET_KEY_FILENAME),
+    glwe_dimension: CORE_CRYPTO_GLWE_DIMENSION,
+    polynomial_size: CORE_CRYPTO_POLYNOMIAL_SIZE,
+};
+
+const CORE_CRYPTO_KSK_TEST: CoreCryptoLweKeyswitchKeyTest = CoreCryptoLweKeyswitchKeyTest {
+    test_filename: Cow::Borrowed("lwe_keyswitch_key"),
+    input_key_filename: Cow::Borrowed(CORE_CRYPTO_LWE_SECRET_KEY_FILENAME),
+    output_key_filename: Cow::Borrowed(CORE_CRYPTO_SMALL_LWE_SECRET_KEY_FILENAME),
+    input_lwe_dimension: CORE_CRYPTO_LWE_DIMENSION,
+    output_lwe_dimension: CORE_CRYPTO_SMALL_LWE_DIMENSION,
+    decomp_base_log: 4,
+    decomp_level_count: 5,
+    ciphertext_modulus: CORE_CRYPTO_CIPHERTEXT_MODULUS,
+    delta: CORE_CRYPTO_DELTA,
+    clear_value: 6,
+};
+
+const CORE_CRYPTO_BSK_TEST: CoreCryptoLweBootstrapKeyTest = CoreCryptoLweBootstrapKeyTest {
+    test_filename: Cow::Borrowed("lwe_bootstrap_key"),
+    input_key_filename: Cow::Borrowed(CORE_CRYPTO_SMALL_LWE_SECRET_KEY_FILENAME),
+    output_key_filename: Cow::Borrowed(CORE_CRYPTO_GLWE_SECRET_KEY_FILENAME),
+    input_lwe_dimension: CORE_CRYPTO_SMALL_LWE_DIMENSION,
+    glwe_dimension: CORE_CRYPTO_GLWE_DIMENSION,
+    polynomial_size: CORE_CRYPTO_POLYNOMIAL_SIZE,
+    decomp_base_log: 15,
+    decomp_level_count: 2,
+    ciphertext_modulus: CORE_CRYPTO_CIPHERTEXT_MODULUS,
+    fourier: false,
+    delta: CORE_CRYPTO_DELTA,
+    clear_value: 3,
+};
+
+const CORE_CRYPTO_FOURIER_BSK_TEST: CoreCryptoLweBootstrapKeyTest = CoreCryptoLweBootstrapKeyTest {
+    test_filename: Cow::Borrowed("fourier_lwe_bootstrap_key"),
+    input_key_filename: CORE_CRYPTO_BSK_TEST.input_key_filename,
+    output_key_filename: CORE_CRYPTO_BSK_TEST.output_key_filename,
+    input_lwe_dimension: CORE_CRYPTO_BSK_TEST.input_lwe_dimension,
+    glwe_dimension: CORE_CRYPTO_BSK_TEST.glwe_dimension,
+    polynomial_size: CORE_CRYPTO_BSK_TEST.polynomial_size,
+    decomp_base_log: CORE_CRYPTO_BSK_TEST.decomp_base_log,
+    decomp_level_count: CORE_CRYPTO_BSK_TEST.decomp_level_count,
+    ciphertext_modulus: CORE_CRYPTO_BSK_TEST.ciphertext_modulus,
+    fourier: true,
+    delta: CORE_CRYPTO_DELTA,
+    clear_value: 13,
+};
+
+const CORE_CRYPTO_MULTI_BIT_BSK_TEST: CoreCryptoLweMultiBitBootstrapKeyTest =
+    CoreCryptoLweMultiBitBootstrapKeyTest {
+        test_filename: Cow::Borrowed("lwe_multi_bit_bootstrap_key"),
+        input_key_filename: Cow::Borrowed(CORE_CRYPTO_SMALL_LWE_SECRET_KEY_FILENAME),
+        output_key_filename: Cow::Borrowed(CORE_CRYPTO_GLWE_SECRET_KEY_FILENAME),
+        input_lwe_dimension: CORE_CRYPTO_SMALL_LWE_DIMENSION,
+        glwe_dimension: CORE_CRYPTO_GLWE_DIMENSION,
+        polynomial_size: CORE_CRYPTO_POLYNOMIAL_SIZE,
+        decomp_base_log: 15,
+        decomp_level_count: 2,
+        grouping_factor: 2,
+        ciphertext_modulus: CORE_CRYPTO_CIPHERTEXT_MODULUS,
+        delta: CORE_CRYPTO_DELTA,
+        clear_value: 9,
+    };
+
+const CORE_CRYPTO_PKSK_TEST: CoreCryptoLwePackingKeyswitchKeyTest =
+    CoreCryptoLwePackingKeyswitchKeyTest {
+        test_filename: Cow::Borrowed("lwe_packing_keyswitch_key"),
+        input_key_filename: Cow::Borrowed(CORE_CRYPTO_SMALL_LWE_SECRET_KEY_FILENAME),
+        output_key_filename: Cow::Borrowed(CORE_CRYPTO_GLWE_SECRET_KEY_FILENAME),
+        input_lwe_dimension: CORE_CRYPTO_SMALL_LWE_DIMENSION,
+        output_glwe_dimension: CORE_CRYPTO_GLWE_DIMENSION,
+        output_polynomial_size: CORE_CRYPTO_POLYNOMIAL_SIZE,
+        decomp_base_log: 8,
+        decomp_level_count: 3,
+        ciphertext_modulus: CORE_CRYPTO_CIPHERTEXT_MODULUS,
+        delta: CORE_CRYPTO_DELTA,
+        clear_value: 10,
+    };
+
+const CORE_CRYPTO_COMPACT_PK_TEST: CoreCryptoLweCompactPublicKeyTest =
+    CoreCryptoLweCompactPublicKeyTest {
+        test_filename: Cow::Borrowed("lwe_compact_public_key"),
+        key_filename: Cow::Borrowed(CORE_CRYPTO_LWE_SECRET_KEY_FILENAME),
+        lwe_dimension: CORE_CRYPTO_LWE_DIMENSION,
+        ciphertext_modulus: CORE_CRYPTO_CIPHERTEXT_MODULUS,
+        delta: CORE_CRYPTO_DELTA,
+        clear_value: 4,
+    };
+
+const CORE_CRYPTO_LWE_CT_TEST: CoreCryptoLweCiphertextTest = CoreCryptoLweCiphertextTest {
+    test_filename: Cow::Borrowed("lwe_ciphertext"),
+    key_filename: Cow::Borrowed(CORE_CRYPTO_LWE_SECRET_KEY_FILENAME),
+    lwe_dimension: CORE_CRYPTO_LWE_DIMENSION,
+    ciphertext_modulus: CORE_CRYPTO_CIPHERTEXT_MODULUS,
+    delta: CORE_CRYPTO_DELTA,
+    clear_value: 11,
+};
+
+const CORE_CRYPTO_LWE_CT_LIST_TEST: CoreCryptoLweCiphertextListTest =
+    CoreCryptoLweCiphertextListTest {
+        test_filename: Cow::Borrowed("lwe_ciphertext_list"),
+        key_filename: Cow::Borrowed(CORE_CRYPTO_LWE_SECRET_KEY_FILENAME),
+        lwe_dimension: CORE_CRYPTO_LWE_DIMENSION,
+        ciphertext_modulus: CORE_CRYPTO_CIPHERTEXT_MODULUS,
+        delta: CORE_CRYPTO_DELTA,
+        clear_values: Cow::Borrowed(&[1, 5, 9, 14]),
+    };
+
+const CORE_CRYPTO_LWE_COMPACT_CT_LIST_TEST: CoreCryptoLweCompactCiphertextListTest =
+    CoreCryptoLweCompactCiphertextListTest {
+        test_filename: Cow::Borrowed("lwe_compact_ciphertext_list"),
+        key_filename: Cow::Borrowed(CORE_CRYPTO_LWE_SECRET_KEY_FILENAME),
+        lwe_dimension: CORE_CRYPTO_LWE_DIMENSION,
+        ciphertext_modulus: CORE_CRYPTO_CIPHERTEXT_MODULUS,
+        delta: CORE_CRYPTO_DELTA,
+        clear_values: Cow::Borrowed(&[7, 0, 3, 12, 15]),
+    };
+
+const CORE_CRYPTO_GLWE_CT_TEST: CoreCryptoGlweCiphertextTest = CoreCryptoGlweCiphertextTest {
+    test_filename: Cow::Borrowed("glwe_ciphertext"),
+    key_filename: Cow::Borrowed(CORE_CRYPTO_GLWE_SECRET_KEY_FILENAME),
+    glwe_dimension: CORE_CRYPTO_GLWE_DIMENSION,
+    polynomial_size: CORE_CRYPTO_POLYNOMIAL_SIZE,
+    ciphertext_modulus: CORE_CRYPTO_CIPHERTEXT_MODULUS,
+    delta: CORE_CRYPTO_DELTA,
+    // The other coefficients of the polynomial are 0
+    clear_values: Cow::Borrowed(&[2, 13, 8, 4]),
+};
+
+const CORE_CRYPTO_GGSW_CT_TEST: CoreCryptoGgswCiphertextTest = CoreCryptoGgswCiphertextTest {
+    test_filename: Cow::Borrowed("ggsw_ciphertext"),
+    key_filename: Cow::Borrowed(CORE_CRYPTO_GLWE_SECRET_KEY_FILENAME),
+    glwe_dimension: CORE_CRYPTO_GLWE_DIMENSION,
+    polynomial_size: CORE_CRYPTO_POLYNOMIAL_SIZE,
+    decomp_base_log: 8,
+    decomp_level_count: 3,
+    ciphertext_modulus: CORE_CRYPTO_CIPHERTEXT_MODULUS,
+    clear_value: 1,
+};
+
+// The seeded entities encrypt the same values and use the same parameters as their regular
+// counterpart. Their metadata holds the digest of their decompressed form, so it is built during
+// the generation.
+const CORE_CRYPTO_SEEDED_LWE_CT_FILENAME: &str = "seeded_lwe_ciphertext";
+const CORE_CRYPTO_SEEDED_GLWE_CT_FILENAME: &str = "seeded_glwe_ciphertext";
+const CORE_CRYPTO_SEEDED_KSK_FILENAME: &str = "seeded_lwe_keyswitch_key";
+const CORE_CRYPTO_SEEDED_BSK_FILENAME: &str = "seeded_lwe_bootstrap_key";
+const CORE_CRYPTO_SEEDED_COMPACT_PK_FILENAME: &str = "seeded_lwe_compact_public_key";
+
+// Boolean
+const BOOLEAN_CLIENT_KEY_FILENAME: &str = "client_key";
+
+// WARNING: these parameters are completely insecure, they are only used to keep the keys small
+const BOOLEAN_CLIENTKEY_TEST: BooleanClientKeyTest = BooleanClientKeyTest {
+    test_filename: Cow::Borrowed(BOOLEAN_CLIENT_KEY_FILENAME),
+    parameters: INSECURE_SMALL_BOOLEAN_TEST_PARAMS,
+};
+
+const BOOLEAN_SERVERKEY_TEST: BooleanServerKeyTest = BooleanServerKeyTest {
+    test_filename: Cow::Borrowed("server_key"),
+    client_key_filename: Cow::Borrowed(BOOLEAN_CLIENT_KEY_FILENAME),
+    compressed: false,
+};
+
+const BOOLEAN_COMPRESSED_SERVERKEY_TEST: BooleanServerKeyTest = BooleanServerKeyTest {
+    test_filename: Cow::Borrowed("compressed_server_key"),
+    client_key_filename: Cow::Borrowed(BOOLEAN_CLIENT_KEY_FILENAME),
+    compressed: true,
+};
+
+const BOOLEAN_PUBKEY_TEST: BooleanPublicKeyTest = BooleanPublicKeyTest {
+    test_filename: Cow::Borrowed("public_key"),
+    client_key_filename: Cow::Borrowed(BOOLEAN_CLIENT_KEY_FILENAME),
+    compressed: false,
+};
+
+const BOOLEAN_COMPRESSED_PUBKEY_TEST: BooleanPublicKeyTest = BooleanPublicKeyTest {
+    test_filename: Cow::Borrowed("compressed_public_key"),
+    client_key_filename: Cow::Borrowed(BOOLEAN_CLIENT_KEY_FILENAME),
+    compressed: true,
+};
+
+const BOOLEAN_CT_TEST: BooleanCiphertextTest = BooleanCiphertextTest {
+    test_filename: Cow::Borrowed("ciphertext"),
+    key_filename: Cow::Borrowed(BOOLEAN_CLIENT_KEY_FILENAME),
+    compressed: false,
+    clear_value: true,
+};
+
+const BOOLEAN_COMPRESSED_CT_TEST: BooleanCiphertextTest = BooleanCiphertextTest {
+    test_filename: Cow::Borrowed("compressed_ciphertext"),
+    key_filename: Cow::Borrowed(BOOLEAN_CLIENT_KEY_FILENAME),
+    compressed: true,
+    clear_value: false,
+};
+
 pub struct V1_1;
 
 impl TfhersVersion for V1_1 {
     const VERSION_NUMBER: &'static str = "1.1";
+    const FROZEN: bool = false;
 
     fn seed_prng(seed: u128) {
         let mut seeder = DeterministicSeeder::<DefaultRandomGenerator>::new(Seed(seed));
@@ -198,14 +843,265 @@ impl TfhersVersion for V1_1 {
         BooleanEngine::replace_thread_local(boolean_engine);
     }
 
-    fn gen_shortint_data() -> Vec<TestMetadata> {
-        Vec::new()
+    fn module_generators() -> Vec<ModuleGenerator> {
+        vec![
+            ModuleGenerator::new(CORE_CRYPTO_MODULE_NAME, Self::gen_core_crypto_data),
+            ModuleGenerator::new(BOOLEAN_MODULE_NAME, Self::gen_boolean_data),
+            ModuleGenerator::new(SHORTINT_MODULE_NAME, Self::gen_shortint_data),
+            ModuleGenerator::new(INTEGER_MODULE_NAME, Self::gen_integer_data),
+            ModuleGenerator::new(HL_MODULE_NAME, Self::gen_hl_data),
+        ]
+    }
+}
+
+impl V1_1 {
+    fn gen_shortint_data(base_data_dir: &Path) -> Vec<TestMetadata> {
+        let dir = Self::data_dir(base_data_dir).join(SHORTINT_MODULE_NAME);
+        create_dir_all(&dir).unwrap();
+
+        let seed_test =
+            |test_filename: &str| Self::seed_prng_for_test(SHORTINT_MODULE_NAME, test_filename);
+
+        // generate keys
+        seed_test(&SHORTINT_CLIENTKEY_TEST.test_filename);
+        let shortint_client_key = shortint::ClientKey::new(SHORTINT_CLIENTKEY_TEST.parameters);
+        seed_test(&SHORTINT_SERVERKEY_TEST.test_filename);
+        let shortint_server_key = shortint::ServerKey::new(&shortint_client_key);
+        seed_test(&SHORTINT_COMPRESSED_SERVERKEY_TEST.test_filename);
+        let shortint_compressed_server_key =
+            shortint::CompressedServerKey::new(&shortint_client_key);
+
+        store_versioned_test!(
+            &shortint_client_key,
+            &dir,
+            &SHORTINT_CLIENTKEY_TEST.test_filename,
+        );
+        store_versioned_test!(
+            &shortint_server_key,
+            &dir,
+            &SHORTINT_SERVERKEY_TEST.test_filename,
+        );
+        store_versioned_test!(
+            &shortint_compressed_server_key,
+            &dir,
+            &SHORTINT_COMPRESSED_SERVERKEY_TEST.test_filename,
+        );
+
+        // generate compressed ciphertexts
+        seed_test(&SHORTINT_COMPRESSED_CT_TEST.test_filename);
+        let compressed_ct =
+            shortint_client_key.encrypt_compressed(SHORTINT_COMPRESSED_CT_TEST.clear_value);
+        seed_test(&SHORTINT_COMPRESSED_CT_MODSWITCHED_TEST.test_filename);
+        let compressed_ct_modswitched = shortint_server_key.switch_modulus_and_compress(
+            &shortint_client_key.encrypt(SHORTINT_COMPRESSED_CT_MODSWITCHED_TEST.clear_value),
+        );
+
+        store_versioned_test!(
+            &compressed_ct,
+            &dir,
+            &SHORTINT_COMPRESSED_CT_TEST.test_filename,
+        );
+        store_versioned_test!(
+            &compressed_ct_modswitched,
+            &dir,
+            &SHORTINT_COMPRESSED_CT_MODSWITCHED_TEST.test_filename,
+        );
+
+        // generate ciphertexts
+        seed_test(&SHORTINT_CT1_TEST.test_filename);
+        let ct1 = shortint_client_key.encrypt(SHORTINT_CT1_TEST.clear_value);
+        seed_test(&SHORTINT_CT2_TEST.test_filename);
+        let ct2 = shortint_client_key.encrypt(SHORTINT_CT2_TEST.clear_value);
+
+        store_versioned_test!(&ct1, &dir, &SHORTINT_CT1_TEST.test_filename);
+        store_versioned_test!(&ct2, &dir, &SHORTINT_CT2_TEST.test_filename);
+
+        // generate public keys
+        seed_test(&SHORTINT_SMALL_CLIENTKEY_TEST.test_filename);
+        let shortint_small_client_key =
+            shortint::ClientKey::new(SHORTINT_SMALL_CLIENTKEY_TEST.parameters);
+        seed_test(&SHORTINT_PUBKEY_TEST.test_filename);
+        let shortint_pub_key = shortint::PublicKey::new(&shortint_small_client_key);
+        seed_test(&SHORTINT_COMPRESSED_PUBKEY_TEST.test_filename);
+        let shortint_compressed_pub_key =
+            shortint::CompressedPublicKey::new(&shortint_small_client_key);
+        seed_test(&SHORTINT_COMPACT_PUBKEY_TEST.test_filename);
+        let shortint_compact_pub_key = shortint::CompactPublicKey::new(&shortint_client_key);
+        seed_test(&SHORTINT_COMPRESSED_COMPACT_PUBKEY_TEST.test_filename);
+        let shortint_compressed_compact_pub_key =
+            shortint::CompressedCompactPublicKey::new(&shortint_client_key);
+
+        store_versioned_test!(
+            &shortint_small_client_key,
+            &dir,
+            &SHORTINT_SMALL_CLIENTKEY_TEST.test_filename,
+        );
+        store_versioned_test!(&shortint_pub_key, &dir, &SHORTINT_PUBKEY_TEST.test_filename,);
+        store_versioned_test!(
+            &shortint_compressed_pub_key,
+            &dir,
+            &SHORTINT_COMPRESSED_PUBKEY_TEST.test_filename,
+        );
+        store_versioned_test!(
+            &shortint_compact_pub_key,
+            &dir,
+            &SHORTINT_COMPACT_PUBKEY_TEST.test_filename,
+        );
+        store_versioned_test!(
+            &shortint_compressed_compact_pub_key,
+            &dir,
+            &SHORTINT_COMPRESSED_COMPACT_PUBKEY_TEST.test_filename,
+        );
+
+        // generate compact lists
+        seed_test(&SHORTINT_COMPACT_LIST_TEST.test_filename);
+        let compact_list =
+            shortint_compact_pub_key.encrypt_slice(&SHORTINT_COMPACT_LIST_TEST.clear_values);
+        seed_test(&SHORTINT_PACKED_COMPACT_LIST_TEST.test_filename);
+        let packed_compact_list = shortint_compact_pub_key.encrypt_iter_with_modulus(
+            SHORTINT_PACKED_COMPACT_LIST_TEST
+                .clear_values
+                .iter()
+                .copied(),
+            (SHORTINT_CLIENTKEY_TEST.parameters.message_modulus
+                * SHORTINT_CLIENTKEY_TEST.parameters.carry_modulus) as u64,
+        );
+
+        store_versioned_test!(
+            &compact_list,
+            &dir,
+            &SHORTINT_COMPACT_LIST_TEST.test_filename,
+        );
+        store_versioned_test!(
+            &packed_compact_list,
+            &dir,
+            &SHORTINT_PACKED_COMPACT_LIST_TEST.test_filename,
+        );
+
+        // generate keyswitching keys
+        seed_test(&SHORTINT_KSK_OUTPUT_CLIENTKEY_TEST.test_filename);
+        let shortint_ksk_output_client_key =
+            shortint::ClientKey::new(SHORTINT_KSK_OUTPUT_CLIENTKEY_TEST.parameters);
+        // The output server keys are stored inside the keyswitching keys
+        seed_test(&SHORTINT_KSK_TEST.test_filename);
+        let ksk = shortint::KeySwitchingKey::new(
+            (&shortint_client_key, Some(&shortint_server_key)),
+            (
+                &shortint_ksk_output_client_key,
+                &shortint::ServerKey::new(&shortint_ksk_output_client_key),
+            ),
+            (&SHORTINT_KSK_TEST).into(),
+        );
+        seed_test(&SHORTINT_COMPRESSED_KSK_TEST.test_filename);
+        let compressed_ksk = shortint::key_switching_key::CompressedKeySwitchingKey::new(
+            (&shortint_client_key, Some(&shortint_compressed_server_key)),
+            (
+                &shortint_ksk_output_client_key,
+                &shortint::CompressedServerKey::new(&shortint_ksk_output_client_key),
+            ),
+            (&SHORTINT_COMPRESSED_KSK_TEST).into(),
+        );
+
+        store_versioned_test!(
+            &shortint_ksk_output_client_key,
+            &dir,
+            &SHORTINT_KSK_OUTPUT_CLIENTKEY_TEST.test_filename,
+        );
+        store_versioned_test!(&ksk, &dir, &SHORTINT_KSK_TEST.test_filename);
+        store_versioned_test!(
+            &compressed_ksk,
+            &dir,
+            &SHORTINT_COMPRESSED_KSK_TEST.test_filename,
+        );
+
+        vec![
+            TestMetadata::ShortintClientKey(SHORTINT_CLIENTKEY_TEST),
+            TestMetadata::ShortintServerKey(SHORTINT_SERVERKEY_TEST),
+            TestMetadata::ShortintServerKey(SHORTINT_COMPRESSED_SERVERKEY_TEST),
+            TestMetadata::ShortintCompressedCiphertext(SHORTINT_COMPRESSED_CT_TEST),
+            TestMetadata::ShortintCompressedCiphertext(SHORTINT_COMPRESSED_CT_MODSWITCHED_TEST),
+            TestMetadata::ShortintCiphertext(SHORTINT_CT1_TEST),
+            TestMetadata::ShortintCiphertext(SHORTINT_CT2_TEST),
+            TestMetadata::ShortintClientKey(SHORTINT_SMALL_CLIENTKEY_TEST),
+            TestMetadata::ShortintPublicKey(SHORTINT_PUBKEY_TEST),
+            TestMetadata::ShortintPublicKey(SHORTINT_COMPRESSED_PUBKEY_TEST),
+            TestMetadata::ShortintPublicKey(SHORTINT_COMPACT_PUBKEY_TEST),
+            TestMetadata::ShortintPublicKey(SHORTINT_COMPRESSED_COMPACT_PUBKEY_TEST),
+            TestMetadata::ShortintCompactCiphertextList(SHORTINT_COMPACT_LIST_TEST),
+            TestMetadata::ShortintCompactCiphertextList(SHORTINT_PACKED_COMPACT_LIST_TEST),
+            TestMetadata::ShortintClientKey(SHORTINT_KSK_OUTPUT_CLIENTKEY_TEST),
+            TestMetadata::ShortintKeySwitchingKey(SHORTINT_KSK_TEST),
+            TestMetadata::ShortintKeySwitchingKey(SHORTINT_COMPRESSED_KSK_TEST),
+        ]
+    }
+
+    fn gen_integer_data(base_data_dir: &Path) -> Vec<TestMetadata> {
+        let dir = Self::data_dir(base_data_dir).join(INTEGER_MODULE_NAME);
+        create_dir_all(&dir).unwrap();
+
+        let seed_test =
+            |test_filename: &str| Self::seed_prng_for_test(INTEGER_MODULE_NAME, test_filename);
+
+        // generate a client key
+        seed_test(&INTEGER_CLIENTKEY_TEST.test_filename);
+        let params: PBSParameters = INTEGER_CLIENTKEY_TEST.parameters.into();
+        let integer_client_key = integer::ClientKey::new(params);
+
+        store_versioned_test!(
+            &integer_client_key,
+            &dir,
+            &INTEGER_CLIENTKEY_TEST.test_filename,
+        );
+
+        // generate ciphertexts
+        seed_test(&INTEGER_RADIX_CT_TEST.test_filename);
+        let radix_ct = integer_client_key.encrypt_radix(
+            INTEGER_RADIX_CT_TEST.clear_value,
+            INTEGER_RADIX_CT_TEST.num_blocks,
+        );
+        seed_test(&INTEGER_SIGNED_RADIX_CT_TEST.test_filename);
+        let signed_radix_ct = integer_client_key.encrypt_signed_radix(
+            INTEGER_SIGNED_RADIX_CT_TEST.clear_value,
+            INTEGER_SIGNED_RADIX_CT_TEST.num_blocks,
+        );
+        seed_test(&INTEGER_CRT_CT_TEST.test_filename);
+        let crt_ct = integer_client_key.encrypt_crt(
+            INTEGER_CRT_CT_TEST.clear_value,
+            INTEGER_CRT_CT_TEST.crt_bases.to_vec(),
+        );
+        seed_test(&INTEGER_BOOLEAN_BLOCK_TEST.test_filename);
+        let boolean_block = integer_client_key.encrypt_bool(INTEGER_BOOLEAN_BLOCK_TEST.clear_value);
+
+        // Serialize them
+        store_versioned_test!(&radix_ct, &dir, &INTEGER_RADIX_CT_TEST.test_filename);
+        store_versioned_test!(
+            &signed_radix_ct,
+            &dir,
+            &INTEGER_SIGNED_RADIX_CT_TEST.test_filename
+        );
+        store_versioned_test!(&crt_ct, &dir, &INTEGER_CRT_CT_TEST.test_filename);
+        store_versioned_test!(
+            &boolean_block,
+            &dir,
+            &INTEGER_BOOLEAN_BLOCK_TEST.test_filename
+        );
+
+        vec![
+            TestMetadata::IntegerClientKey(INTEGER_CLIENTKEY_TEST),
+            TestMetadata::IntegerRadixCiphertext(INTEGER_RADIX_CT_TEST),
+            TestMetadata::IntegerSignedRadixCiphertext(INTEGER_SIGNED_RADIX_CT_TEST),
+            TestMetadata::IntegerCrtCiphertext(INTEGER_CRT_CT_TEST),
+            TestMetadata::IntegerBooleanBlock(INTEGER_BOOLEAN_BLOCK_TEST),
+        ]
     }
 
-    fn gen_hl_data() -> Vec<TestMetadata> {
-        let dir = Self::data_dir().join(HL_MODULE_NAME);
+    fn gen_hl_data(base_data_dir: &Path) -> Vec<TestMetadata> {
+        let dir = Self::data_dir(base_data_dir).join(HL_MODULE_NAME);
         create_dir_all(&dir).unwrap();
 
+        let seed_test =
+            |test_filename: &str| Self::seed_prng_for_test(HL_MODULE_NAME, test_filename);
+
         let config = tfhe_1_1::ConfigBuilder::with_custom_parameters(
             HL_CLIENTKEY_WITH_NOISE_SQUASHING_TEST.parameters,
         )
@@ -214,22 +1110,29 @@ impl TfhersVersion for V1_1 {
         )
         .build();
 
-        let (hl_client_key, hl_server_key) = tfhe_1_1::generate_keys(config);
+        seed_test(&HL_CLIENTKEY_WITH_NOISE_SQUASHING_TEST.test_filename);
+        let hl_client_key = ClientKey::generate(config);
+        seed_test(&HL_SERVERKEY_MS_NOISE_REDUCTION_TEST.test_filename);
+        let hl_server_key = ServerKey::new(&hl_client_key);
 
         set_server_key(hl_server_key.clone());
 
+        seed_test(&HL_SQUASHED_NOISE_UNSIGNED_CIPHERTEXT_TEST.test_filename);
         let ct_unsigned = FheUint64::encrypt(
             HL_SQUASHED_NOISE_UNSIGNED_CIPHERTEXT_TEST.clear_value,
             &hl_client_key,
         );
+        seed_test(&HL_SQUASHED_NOISE_SIGNED_CIPHERTEXT_TEST.test_filename);
         let ct_signed = FheInt64::encrypt(
             HL_SQUASHED_NOISE_SIGNED_CIPHERTEXT_TEST.clear_value,
             &hl_client_key,
         );
+        seed_test(&HL_SQUASHED_NOISE_BOOL_FALSE_CIPHERTEXT_TEST.test_filename);
         let ct_false = FheBool::encrypt(
             HL_SQUASHED_NOISE_BOOL_FALSE_CIPHERTEXT_TEST.clear_value,
             &hl_client_key,
         );
+        seed_test(&HL_SQUASHED_NOISE_BOOL_TRUE_CIPHERTEXT_TEST.test_filename);
         let ct_true = FheBool::encrypt(
             HL_SQUASHED_NOISE_BOOL_TRUE_CIPHERTEXT_TEST.clear_value,
             &hl_client_key,
@@ -272,6 +1175,7 @@ impl TfhersVersion for V1_1 {
             &HL_SQUASHED_NOISE_BOOL_TRUE_CIPHERTEXT_TEST.test_filename,
         );
 
+        seed_test(&HL_SERVERKEY_MS_NOISE_REDUCTION_COMPRESSED_TEST.test_filename);
         let compressed_hl_server_key = CompressedServerKey::new(&hl_client_key);
 
         store_versioned_test!(
@@ -280,6 +1184,140 @@ impl TfhersVersion for V1_1 {
             &HL_SERVERKEY_MS_NOISE_REDUCTION_COMPRESSED_TEST.test_filename,
         );
 
+        // Generate a compressed list that mixes integers and strings
+        let config = tfhe_1_1::ConfigBuilder::with_custom_parameters(
+            HL_CLIENTKEY_WITH_COMPRESSION_TEST.parameters,
+        )
+        .enable_compression(VALID_TEST_PARAMS_TUNIFORM_COMPRESSION.into())
+        .build();
+
+        seed_test(&HL_CLIENTKEY_WITH_COMPRESSION_TEST.test_filename);
+        let hl_client_key = ClientKey::generate(config);
+        let hl_server_key = ServerKey::new(&hl_client_key);
+        set_server_key(hl_server_key);
+
+        let clear_values = &HL_COMPRESSED_LIST_WITH_STRINGS_TEST.clear_values;
+        let clear_string = |index: usize| match &clear_values[index] {
+            TestClearValue::String(value) => value.to_string(),
+            _ => panic!("Expected a string clear value"),
+        };
+        let clear_integer = |index: usize| {
+            clear_values[index]
+                .low_u64()
+                .expect("Expected an integer clear value")
+        };
+
+        seed_test(&HL_COMPRESSED_LIST_WITH_STRINGS_TEST.test_filename);
+        let mut compressed_builder = CompressedCiphertextListBuilder::new();
+        compressed_builder
+            .push(FheUint8::encrypt(clear_integer(0) as u8, &hl_client_key))
+            .push(FheAsciiString::try_encrypt(&clear_string(1), &hl_client_key).unwrap())
+            .push(FheInt16::encrypt(clear_integer(2) as i16, &hl_client_key))
+            .push(FheAsciiString::try_encrypt(&clear_string(3), &hl_client_key).unwrap())
+            .push(FheBool::encrypt(clear_integer(4) != 0, &hl_client_key));
+        let compressed_list = compressed_builder.build().unwrap();
+
+        store_versioned_test!(
+            &hl_client_key,
+            &dir,
+            &HL_CLIENTKEY_WITH_COMPRESSION_TEST.test_filename,
+        );
+        store_versioned_test!(
+            &compressed_list,
+            &dir,
+            &HL_COMPRESSED_LIST_WITH_STRINGS_TEST.test_filename,
+        );
+
+        // The ciphertexts of the width sweep are encrypted with their own key, as in the other
+        // versions.
+        let config = tfhe_1_1::ConfigBuilder::with_custom_parameters(
+            HL_CLIENTKEY_WITH_COMPRESSION_TEST.parameters,
+        )
+        .build();
+        seed_test(HL_WIDE_INTEGERS_CLIENT_KEY_FILENAME);
+        let hl_client_key = ClientKey::generate(config);
+
+        store_versioned_auxiliary!(&hl_client_key, &dir, HL_WIDE_INTEGERS_CLIENT_KEY_FILENAME);
+
+        // Generate ciphertexts for a range of integer widths
+        seed_test(&HL_UINT4_CT_TEST.test_filename);
+        let ct_uint4 =
+            FheUint4::encrypt(HL_UINT4_CT_TEST.clear_value.low_u64() as u8, &hl_client_key);
+        store_versioned_test!(&ct_uint4, &dir, &HL_UINT4_CT_TEST.test_filename);
+        seed_test(&HL_UINT16_CT_TEST.test_filename);
+        let ct_uint16 = FheUint16::encrypt(
+            HL_UINT16_CT_TEST.clear_value.low_u64() as u16,
+            &hl_client_key,
+        );
+        store_versioned_test!(&ct_uint16, &dir, &HL_UINT16_CT_TEST.test_filename);
+        seed_test(&HL_UINT32_CT_TEST.test_filename);
+        let ct_uint32 = FheUint32::encrypt(
+            HL_UINT32_CT_TEST.clear_value.low_u64() as u32,
+            &hl_client_key,
+        );
+        store_versioned_test!(&ct_uint32, &dir, &HL_UINT32_CT_TEST.test_filename);
+        seed_test(&HL_UINT64_CT_TEST.test_filename);
+        let ct_uint64 = FheUint64::encrypt(HL_UINT64_CT_TEST.clear_value.low_u64(), &hl_client_key);
+        store_versioned_test!(&ct_uint64, &dir, &HL_UINT64_CT_TEST.test_filename);
+        seed_test(&HL_UINT128_CT_TEST.test_filename);
+        let ct_uint128 =
+            FheUint128::encrypt(HL_UINT128_CT_TEST.clear_value.low_u128(), &hl_client_key);
+        store_versioned_test!(&ct_uint128, &dir, &HL_UINT128_CT_TEST.test_filename);
+        seed_test(&HL_UINT160_CT_TEST.test_filename);
+        let ct_uint160 = FheUint160::encrypt(
+            U256::from((
+                HL_UINT160_CT_TEST.clear_value.low_u128(),
+                HL_UINT160_CT_TEST.clear_value.high_u128(),
+            )),
+            &hl_client_key,
+        );
+        store_versioned_test!(&ct_uint160, &dir, &HL_UINT160_CT_TEST.test_filename);
+        seed_test(&HL_UINT256_CT_TEST.test_filename);
+        let ct_uint256 = FheUint256::encrypt(
+            U256::from((
+                HL_UINT256_CT_TEST.clear_value.low_u128(),
+                HL_UINT256_CT_TEST.clear_value.high_u128(),
+            )),
+            &hl_client_key,
+        );
+        store_versioned_test!(&ct_uint256, &dir, &HL_UINT256_CT_TEST.test_filename);
+        seed_test(&HL_INT16_CT_TEST.test_filename);
+        let ct_int16 = FheInt16::encrypt(
+            HL_INT16_CT_TEST.clear_value.low_u64() as i16,
+            &hl_client_key,
+        );
+        store_versioned_test!(&ct_int16, &dir, &HL_INT16_CT_TEST.test_filename);
+        seed_test(&HL_INT64_CT_TEST.test_filename);
+        let ct_int64 = FheInt64::encrypt(
+            HL_INT64_CT_TEST.clear_value.low_u64() as i64,
+            &hl_client_key,
+        );
+        store_versioned_test!(&ct_int64, &dir, &HL_INT64_CT_TEST.test_filename);
+        seed_test(&HL_INT128_CT_TEST.test_filename);
+        let ct_int128 = FheInt128::encrypt(
+            HL_INT128_CT_TEST.clear_value.low_u128() as i128,
+            &hl_client_key,
+        );
+        store_versioned_test!(&ct_int128, &dir, &HL_INT128_CT_TEST.test_filename);
+        seed_test(&HL_INT160_CT_TEST.test_filename);
+        let ct_int160 = FheInt160::encrypt(
+            I256::from((
+                HL_INT160_CT_TEST.clear_value.low_u128(),
+                HL_INT160_CT_TEST.clear_value.high_u128(),
+            )),
+            &hl_client_key,
+        );
+        store_versioned_test!(&ct_int160, &dir, &HL_INT160_CT_TEST.test_filename);
+        seed_test(&HL_INT256_CT_TEST.test_filename);
+        let ct_int256 = FheInt256::encrypt(
+            I256::from((
+                HL_INT256_CT_TEST.clear_value.low_u128(),
+                HL_INT256_CT_TEST.clear_value.high_u128(),
+            )),
+            &hl_client_key,
+        );
+        store_versioned_test!(&ct_int256, &dir, &HL_INT256_CT_TEST.test_filename);
+
         vec![
             TestMetadata::HlClientKey(HL_CLIENTKEY_WITH_NOISE_SQUASHING_TEST),
             TestMetadata::HlServerKey(HL_SERVERKEY_MS_NOISE_REDUCTION_TEST),
@@ -294,6 +1332,474 @@ impl TfhersVersion for V1_1 {
             TestMetadata::HlSquashedNoiseBoolCiphertext(
                 HL_SQUASHED_NOISE_BOOL_TRUE_CIPHERTEXT_TEST,
             ),
+            TestMetadata::HlClientKey(HL_CLIENTKEY_WITH_COMPRESSION_TEST),
+            TestMetadata::HlTypedCiphertextList(HL_COMPRESSED_LIST_WITH_STRINGS_TEST),
+            TestMetadata::HlWideCiphertext(HL_UINT4_CT_TEST),
+            TestMetadata::HlWideCiphertext(HL_UINT16_CT_TEST),
+            TestMetadata::HlWideCiphertext(HL_UINT32_CT_TEST),
+            TestMetadata::HlWideCiphertext(HL_UINT64_CT_TEST),
+            TestMetadata::HlWideCiphertext(HL_UINT128_CT_TEST),
+            TestMetadata::HlWideCiphertext(HL_UINT160_CT_TEST),
+            TestMetadata::HlWideCiphertext(HL_UINT256_CT_TEST),
+            TestMetadata::HlSignedWideCiphertext(HL_INT16_CT_TEST),
+            TestMetadata::HlSignedWideCiphertext(HL_INT64_CT_TEST),
+            TestMetadata::HlSignedWideCiphertext(HL_INT128_CT_TEST),
+            TestMetadata::HlSignedWideCiphertext(HL_INT160_CT_TEST),
+            TestMetadata::HlSignedWideCiphertext(HL_INT256_CT_TEST),
+        ]
+    }
+
+    fn gen_core_crypto_data(base_data_dir: &Path) -> Vec<TestMetadata> {
+        let dir = Self::data_dir(base_data_dir).join(CORE_CRYPTO_MODULE_NAME);
+        create_dir_all(&dir).unwrap();
+
+        // core_crypto does not use the thread local engines, so each object gets its own seeder
+        let seeder_for_test = |test_filename: &str| {
+            DeterministicSeeder::<DefaultRandomGenerator>::new(Seed(test_seed(
+                Self::VERSION_NUMBER,
+                CORE_CRYPTO_MODULE_NAME,
+                test_filename,
+            )))
+        };
+        let encryption_generator_for_test = |test_filename: &str| {
+            let mut seeder = seeder_for_test(test_filename);
+            EncryptionRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed(), &mut seeder)
+        };
+        let secret_generator_for_test = |test_filename: &str| {
+            let mut seeder = seeder_for_test(test_filename);
+            SecretRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed())
+        };
+
+        let lwe_dimension = LweDimension(CORE_CRYPTO_LWE_DIMENSION);
+        let glwe_dimension = GlweDimension(CORE_CRYPTO_GLWE_DIMENSION);
+        let polynomial_size = PolynomialSize(CORE_CRYPTO_POLYNOMIAL_SIZE);
+        let ciphertext_modulus =
+            CiphertextModulus::try_new(CORE_CRYPTO_CIPHERTEXT_MODULUS).unwrap();
+        let noise_distribution: DynamicDistribution<u64> = CORE_CRYPTO_NOISE_DISTRIBUTION.into();
+
+        // generate the secret keys
+        let lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+            lwe_dimension,
+            &mut secret_generator_for_test(&CORE_CRYPTO_LWE_SECRET_KEY_TEST.test_filename),
+        );
+        let small_lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+            LweDimension(CORE_CRYPTO_SMALL_LWE_SECRET_KEY_TEST.lwe_dimension),
+            &mut secret_generator_for_test(&CORE_CRYPTO_SMALL_LWE_SECRET_KEY_TEST.test_filename),
+        );
+        let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+            glwe_dimension,
+            polynomial_size,
+            &mut secret_generator_for_test(&CORE_CRYPTO_GLWE_SECRET_KEY_TEST.test_filename),
+        );
+
+        store_versioned_test!(
+            &lwe_secret_key,
+            &dir,
+            &CORE_CRYPTO_LWE_SECRET_KEY_TEST.test_filename
+        );
+        store_versioned_test!(
+            &small_lwe_secret_key,
+            &dir,
+            &CORE_CRYPTO_SMALL_LWE_SECRET_KEY_TEST.test_filename
+        );
+        store_versioned_test!(
+            &glwe_secret_key,
+            &dir,
+            &CORE_CRYPTO_GLWE_SECRET_KEY_TEST.test_filename
+        );
+
+        // generate the keyswitching, bootstrapping and public keys
+        let ksk = allocate_and_generate_new_lwe_keyswitch_key(
+            &lwe_secret_key,
+            &small_lwe_secret_key,
+            DecompositionBaseLog(CORE_CRYPTO_KSK_TEST.decomp_base_log),
+            DecompositionLevelCount(CORE_CRYPTO_KSK_TEST.decomp_level_count),
+            noise_distribution,
+            ciphertext_modulus,
+            &mut encryption_generator_for_test(&CORE_CRYPTO_KSK_TEST.test_filename),
+        );
+
+        let bsk = allocate_and_generate_new_lwe_bootstrap_key(
+            &small_lwe_secret_key,
+            &glwe_secret_key,
+            DecompositionBaseLog(CORE_CRYPTO_BSK_TEST.decomp_base_log),
+            DecompositionLevelCount(CORE_CRYPTO_BSK_TEST.decomp_level_count),
+            noise_distribution,
+            ciphertext_modulus,
+            &mut encryption_generator_for_test(&CORE_CRYPTO_BSK_TEST.test_filename),
+        );
+
+        // The fourier key is converted from its own standard key
+        let bsk_for_fourier = allocate_and_generate_new_lwe_bootstrap_key(
+            &small_lwe_secret_key,
+            &glwe_secret_key,
+            DecompositionBaseLog(CORE_CRYPTO_FOURIER_BSK_TEST.decomp_base_log),
+            DecompositionLevelCount(CORE_CRYPTO_FOURIER_BSK_TEST.decomp_level_count),
+            noise_distribution,
+            ciphertext_modulus,
+            &mut encryption_generator_for_test(&CORE_CRYPTO_FOURIER_BSK_TEST.test_filename),
+        );
+        let mut fourier_bsk = FourierLweBootstrapKey::new(
+            bsk_for_fourier.input_lwe_dimension(),
+            bsk_for_fourier.glwe_size(),
+            bsk_for_fourier.polynomial_size(),
+            bsk_for_fourier.decomposition_base_log(),
+            bsk_for_fourier.decomposition_level_count(),
+        );
+        convert_standard_lwe_bootstrap_key_to_fourier(&bsk_for_fourier, &mut fourier_bsk);
+
+        let multi_bit_bsk = allocate_and_generate_new_lwe_multi_bit_bootstrap_key(
+            &small_lwe_secret_key,
+            &glwe_secret_key,
+            DecompositionBaseLog(CORE_CRYPTO_MULTI_BIT_BSK_TEST.decomp_base_log),
+            DecompositionLevelCount(CORE_CRYPTO_MULTI_BIT_BSK_TEST.decomp_level_count),
+            LweBskGroupingFactor(CORE_CRYPTO_MULTI_BIT_BSK_TEST.grouping_factor),
+            noise_distribution,
+            ciphertext_modulus,
+            &mut encryption_generator_for_test(&CORE_CRYPTO_MULTI_BIT_BSK_TEST.test_filename),
+        );
+
+        let pksk = allocate_and_generate_new_lwe_packing_keyswitch_key(
+            &small_lwe_secret_key,
+            &glwe_secret_key,
+            DecompositionBaseLog(CORE_CRYPTO_PKSK_TEST.decomp_base_log),
+            DecompositionLevelCount(CORE_CRYPTO_PKSK_TEST.decomp_level_count),
+            noise_distribution,
+            ciphertext_modulus,
+            &mut encryption_generator_for_test(&CORE_CRYPTO_PKSK_TEST.test_filename),
+        );
+
+        let compact_pk = allocate_and_generate_new_lwe_compact_public_key(
+            &lwe_secret_key,
+            noise_distribution,
+            ciphertext_modulus,
+            &mut encryption_generator_for_test(&CORE_CRYPTO_COMPACT_PK_TEST.test_filename),
+        );
+
+        store_versioned_test!(&ksk, &dir, &CORE_CRYPTO_KSK_TEST.test_filename);
+        store_versioned_test!(&bsk, &dir, &CORE_CRYPTO_BSK_TEST.test_filename);
+        store_versioned_test!(
+            &fourier_bsk,
+            &dir,
+            &CORE_CRYPTO_FOURIER_BSK_TEST.test_filename
+        );
+        store_versioned_test!(
+            &multi_bit_bsk,
+            &dir,
+            &CORE_CRYPTO_MULTI_BIT_BSK_TEST.test_filename
+        );
+        store_versioned_test!(&pksk, &dir, &CORE_CRYPTO_PKSK_TEST.test_filename);
+        store_versioned_test!(
+            &compact_pk,
+            &dir,
+            &CORE_CRYPTO_COMPACT_PK_TEST.test_filename
+        );
+
+        // generate ciphertexts
+        let encode = |clear_values: &[u64], delta: u64| {
+            clear_values
+                .iter()
+                .map(|value| value * delta)
+                .collect::<Vec<_>>()
+        };
+
+        let lwe_ct = allocate_and_encrypt_new_lwe_ciphertext(
+            &lwe_secret_key,
+            Plaintext(CORE_CRYPTO_LWE_CT_TEST.clear_value * CORE_CRYPTO_LWE_CT_TEST.delta),
+            noise_distribution,
+            ciphertext_modulus,
+            &mut encryption_generator_for_test(&CORE_CRYPTO_LWE_CT_TEST.test_filename),
+        );
+
+        let mut lwe_ct_list = LweCiphertextList::new(
+            0u64,
+            lwe_dimension.to_lwe_size(),
+            LweCiphertextCount(CORE_CRYPTO_LWE_CT_LIST_TEST.clear_values.len()),
+            ciphertext_modulus,
+        );
+        encrypt_lwe_ciphertext_list(
+            &lwe_secret_key,
+            &mut lwe_ct_list,
+            &PlaintextList::from_container(encode(
+                &CORE_CRYPTO_LWE_CT_LIST_TEST.clear_values,
+                CORE_CRYPTO_LWE_CT_LIST_TEST.delta,
+            )),
+            noise_distribution,
+            &mut encryption_generator_for_test(&CORE_CRYPTO_LWE_CT_LIST_TEST.test_filename),
+        );
+
+        // The compact public key is only used to encrypt the list, it is not stored
+        let mut seeder = seeder_for_test(&CORE_CRYPTO_LWE_COMPACT_CT_LIST_TEST.test_filename);
+        let mut compact_list_secret_generator =
+            SecretRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed());
+        let mut compact_list_encryption_generator =
+            EncryptionRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed(), &mut seeder);
+        let compact_public_key = allocate_and_generate_new_lwe_compact_public_key(
+            &lwe_secret_key,
+            noise_distribution,
+            ciphertext_modulus,
+            &mut compact_list_encryption_generator,
+        );
+        let mut lwe_compact_ct_list = LweCompactCiphertextList::new(
+            0u64,
+            lwe_dimension.to_lwe_size(),
+            LweCiphertextCount(CORE_CRYPTO_LWE_COMPACT_CT_LIST_TEST.clear_values.len()),
+            ciphertext_modulus,
+        );
+        encrypt_lwe_compact_ciphertext_list_with_compact_public_key(
+            &compact_public_key,
+            &mut lwe_compact_ct_list,
+            &PlaintextList::from_container(encode(
+                &CORE_CRYPTO_LWE_COMPACT_CT_LIST_TEST.clear_values,
+                CORE_CRYPTO_LWE_COMPACT_CT_LIST_TEST.delta,
+            )),
+            noise_distribution,
+            noise_distribution,
+            &mut compact_list_secret_generator,
+            &mut compact_list_encryption_generator,
+        );
+
+        let mut glwe_plaintexts = encode(
+            &CORE_CRYPTO_GLWE_CT_TEST.clear_values,
+            CORE_CRYPTO_GLWE_CT_TEST.delta,
+        );
+        glwe_plaintexts.resize(polynomial_size.0, 0);
+        let mut glwe_ct = GlweCiphertext::new(
+            0u64,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            ciphertext_modulus,
+        );
+        encrypt_glwe_ciphertext(
+            &glwe_secret_key,
+            &mut glwe_ct,
+            &PlaintextList::from_container(glwe_plaintexts.clone()),
+            noise_distribution,
+            &mut encryption_generator_for_test(&CORE_CRYPTO_GLWE_CT_TEST.test_filename),
+        );
+
+        let mut ggsw_ct = GgswCiphertext::new(
+            0u64,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            DecompositionBaseLog(CORE_CRYPTO_GGSW_CT_TEST.decomp_base_log),
+            DecompositionLevelCount(CORE_CRYPTO_GGSW_CT_TEST.decomp_level_count),
+            ciphertext_modulus,
+        );
+        encrypt_constant_ggsw_ciphertext(
+            &glwe_secret_key,
+            &mut ggsw_ct,
+            Cleartext(CORE_CRYPTO_GGSW_CT_TEST.clear_value),
+            noise_distribution,
+            &mut encryption_generator_for_test(&CORE_CRYPTO_GGSW_CT_TEST.test_filename),
+        );
+
+        // Serialize them
+        store_versioned_test!(&lwe_ct, &dir, &CORE_CRYPTO_LWE_CT_TEST.test_filename);
+        store_versioned_test!(
+            &lwe_ct_list,
+            &dir,
+            &CORE_CRYPTO_LWE_CT_LIST_TEST.test_filename
+        );
+        store_versioned_test!(
+            &lwe_compact_ct_list,
+            &dir,
+            &CORE_CRYPTO_LWE_COMPACT_CT_LIST_TEST.test_filename
+        );
+        store_versioned_test!(&glwe_ct, &dir, &CORE_CRYPTO_GLWE_CT_TEST.test_filename);
+        store_versioned_test!(&ggsw_ct, &dir, &CORE_CRYPTO_GGSW_CT_TEST.test_filename);
+
+        // generate the seeded entities
+        let seeded_test =
+            |test_filename: &str, entity: CoreCryptoSeededEntity, decompressed: &[u64]| {
+                TestMetadata::CoreCryptoSeededEntity(CoreCryptoSeededEntityTest {
+                    test_filename: Cow::Owned(test_filename.to_string()),
+                    entity,
+                    decompressed_digest: Cow::Owned(container_digest(decompressed)),
+                })
+            };
+
+        let seeded_lwe_ct = allocate_and_encrypt_new_seeded_lwe_ciphertext(
+            &lwe_secret_key,
+            Plaintext(CORE_CRYPTO_LWE_CT_TEST.clear_value * CORE_CRYPTO_LWE_CT_TEST.delta),
+            noise_distribution,
+            ciphertext_modulus,
+            &mut seeder_for_test(CORE_CRYPTO_SEEDED_LWE_CT_FILENAME),
+        );
+        store_versioned_test!(&seeded_lwe_ct, &dir, CORE_CRYPTO_SEEDED_LWE_CT_FILENAME);
+        let seeded_lwe_ct_test = seeded_test(
+            CORE_CRYPTO_SEEDED_LWE_CT_FILENAME,
+            CoreCryptoSeededEntity::SeededLweCiphertext,
+            seeded_lwe_ct.decompress_into_lwe_ciphertext().as_ref(),
+        );
+
+        let mut seeded_glwe_ct_seeder = seeder_for_test(CORE_CRYPTO_SEEDED_GLWE_CT_FILENAME);
+        let mut seeded_glwe_ct = SeededGlweCiphertext::new(
+            0u64,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            seeded_glwe_ct_seeder.seed().into(),
+            ciphertext_modulus,
+        );
+        encrypt_seeded_glwe_ciphertext(
+            &glwe_secret_key,
+            &mut seeded_glwe_ct,
+            &PlaintextList::from_container(glwe_plaintexts),
+            noise_distribution,
+            &mut seeded_glwe_ct_seeder,
+        );
+        store_versioned_test!(&seeded_glwe_ct, &dir, CORE_CRYPTO_SEEDED_GLWE_CT_FILENAME);
+        let seeded_glwe_ct_test = seeded_test(
+            CORE_CRYPTO_SEEDED_GLWE_CT_FILENAME,
+            CoreCryptoSeededEntity::SeededGlweCiphertext,
+            seeded_glwe_ct.decompress_into_glwe_ciphertext().as_ref(),
+        );
+
+        let seeded_ksk = allocate_and_generate_new_seeded_lwe_keyswitch_key(
+            &lwe_secret_key,
+            &small_lwe_secret_key,
+            DecompositionBaseLog(CORE_CRYPTO_KSK_TEST.decomp_base_log),
+            DecompositionLevelCount(CORE_CRYPTO_KSK_TEST.decomp_level_count),
+            noise_distribution,
+            ciphertext_modulus,
+            &mut seeder_for_test(CORE_CRYPTO_SEEDED_KSK_FILENAME),
+        );
+        store_versioned_test!(&seeded_ksk, &dir, CORE_CRYPTO_SEEDED_KSK_FILENAME);
+        let seeded_ksk_test = seeded_test(
+            CORE_CRYPTO_SEEDED_KSK_FILENAME,
+            CoreCryptoSeededEntity::SeededLweKeyswitchKey,
+            seeded_ksk.decompress_into_lwe_keyswitch_key().as_ref(),
+        );
+
+        let seeded_bsk = allocate_and_generate_new_seeded_lwe_bootstrap_key(
+            &small_lwe_secret_key,
+            &glwe_secret_key,
+            DecompositionBaseLog(CORE_CRYPTO_BSK_TEST.decomp_base_log),
+            DecompositionLevelCount(CORE_CRYPTO_BSK_TEST.decomp_level_count),
+            noise_distribution,
+            ciphertext_modulus,
+            &mut seeder_for_test(CORE_CRYPTO_SEEDED_BSK_FILENAME),
+        );
+        store_versioned_test!(&seeded_bsk, &dir, CORE_CRYPTO_SEEDED_BSK_FILENAME);
+        let seeded_bsk_test = seeded_test(
+            CORE_CRYPTO_SEEDED_BSK_FILENAME,
+            CoreCryptoSeededEntity::SeededLweBootstrapKey,
+            seeded_bsk.decompress_into_lwe_bootstrap_key().as_ref(),
+        );
+
+        let seeded_compact_pk = allocate_and_generate_new_seeded_lwe_compact_public_key(
+            &lwe_secret_key,
+            noise_distribution,
+            ciphertext_modulus,
+            &mut seeder_for_test(CORE_CRYPTO_SEEDED_COMPACT_PK_FILENAME),
+        );
+        store_versioned_test!(
+            &seeded_compact_pk,
+            &dir,
+            CORE_CRYPTO_SEEDED_COMPACT_PK_FILENAME
+        );
+        let seeded_compact_pk_test = seeded_test(
+            CORE_CRYPTO_SEEDED_COMPACT_PK_FILENAME,
+            CoreCryptoSeededEntity::SeededLweCompactPublicKey,
+            seeded_compact_pk
+                .decompress_into_lwe_compact_public_key()
+                .as_ref(),
+        );
+
+        vec![
+            TestMetadata::CoreCryptoLweSecretKey(CORE_CRYPTO_LWE_SECRET_KEY_TEST),
+            TestMetadata::CoreCryptoLweSecretKey(CORE_CRYPTO_SMALL_LWE_SECRET_KEY_TEST),
+            TestMetadata::CoreCryptoGlweSecretKey(CORE_CRYPTO_GLWE_SECRET_KEY_TEST),
+            TestMetadata::CoreCryptoLweKeyswitchKey(CORE_CRYPTO_KSK_TEST),
+            TestMetadata::CoreCryptoLweBootstrapKey(CORE_CRYPTO_BSK_TEST),
+            TestMetadata::CoreCryptoLweBootstrapKey(CORE_CRYPTO_FOURIER_BSK_TEST),
+            TestMetadata::CoreCryptoLweMultiBitBootstrapKey(CORE_CRYPTO_MULTI_BIT_BSK_TEST),
+            TestMetadata::CoreCryptoLwePackingKeyswitchKey(CORE_CRYPTO_PKSK_TEST),
+            TestMetadata::CoreCryptoLweCompactPublicKey(CORE_CRYPTO_COMPACT_PK_TEST),
+            TestMetadata::CoreCryptoLweCiphertext(CORE_CRYPTO_LWE_CT_TEST),
+            TestMetadata::CoreCryptoLweCiphertextList(CORE_CRYPTO_LWE_CT_LIST_TEST),
+            TestMetadata::CoreCryptoLweCompactCiphertextList(CORE_CRYPTO_LWE_COMPACT_CT_LIST_TEST),
+            TestMetadata::CoreCryptoGlweCiphertext(CORE_CRYPTO_GLWE_CT_TEST),
+            TestMetadata::CoreCryptoGgswCiphertext(CORE_CRYPTO_GGSW_CT_TEST),
+            seeded_lwe_ct_test,
+            seeded_glwe_ct_test,
+            seeded_ksk_test,
+            seeded_bsk_test,
+            seeded_compact_pk_test,
+        ]
+    }
+
+    fn gen_boolean_data(base_data_dir: &Path) -> Vec<TestMetadata> {
+        let dir = Self::data_dir(base_data_dir).join(BOOLEAN_MODULE_NAME);
+        create_dir_all(&dir).unwrap();
+
+        let seed_test =
+            |test_filename: &str| Self::seed_prng_for_test(BOOLEAN_MODULE_NAME, test_filename);
+
+        // generate keys
+        seed_test(&BOOLEAN_CLIENTKEY_TEST.test_filename);
+        let boolean_client_key =
+            boolean::client_key::ClientKey::new(&BOOLEAN_CLIENTKEY_TEST.parameters.into());
+        seed_test(&BOOLEAN_SERVERKEY_TEST.test_filename);
+        let boolean_server_key = boolean::server_key::ServerKey::new(&boolean_client_key);
+        seed_test(&BOOLEAN_COMPRESSED_SERVERKEY_TEST.test_filename);
+        let boolean_compressed_server_key =
+            boolean::server_key::CompressedServerKey::new(&boolean_client_key);
+        seed_test(&BOOLEAN_PUBKEY_TEST.test_filename);
+        let boolean_public_key = boolean::public_key::PublicKey::new(&boolean_client_key);
+        seed_test(&BOOLEAN_COMPRESSED_PUBKEY_TEST.test_filename);
+        let boolean_compressed_public_key =
+            boolean::public_key::CompressedPublicKey::new(&boolean_client_key);
+
+        store_versioned_test!(
+            &boolean_client_key,
+            &dir,
+            &BOOLEAN_CLIENTKEY_TEST.test_filename,
+        );
+        store_versioned_test!(
+            &boolean_server_key,
+            &dir,
+            &BOOLEAN_SERVERKEY_TEST.test_filename,
+        );
+        store_versioned_test!(
+            &boolean_compressed_server_key,
+            &dir,
+            &BOOLEAN_COMPRESSED_SERVERKEY_TEST.test_filename,
+        );
+        store_versioned_test!(
+            &boolean_public_key,
+            &dir,
+            &BOOLEAN_PUBKEY_TEST.test_filename,
+        );
+        store_versioned_test!(
+            &boolean_compressed_public_key,
+            &dir,
+            &BOOLEAN_COMPRESSED_PUBKEY_TEST.test_filename,
+        );
+
+        // generate ciphertexts
+        seed_test(&BOOLEAN_CT_TEST.test_filename);
+        let ct = boolean_client_key.encrypt(BOOLEAN_CT_TEST.clear_value);
+        seed_test(&BOOLEAN_COMPRESSED_CT_TEST.test_filename);
+        let compressed_ct =
+            boolean_client_key.encrypt_compressed(BOOLEAN_COMPRESSED_CT_TEST.clear_value);
+
+        // Serialize them
+        store_versioned_test!(&ct, &dir, &BOOLEAN_CT_TEST.test_filename);
+        store_versioned_test!(
+            &compressed_ct,
+            &dir,
+            &BOOLEAN_COMPRESSED_CT_TEST.test_filename
+        );
+
+        vec![
+            TestMetadata::BooleanClientKey(BOOLEAN_CLIENTKEY_TEST),
+            TestMetadata::BooleanServerKey(BOOLEAN_SERVERKEY_TEST),
+            TestMetadata::BooleanServerKey(BOOLEAN_COMPRESSED_SERVERKEY_TEST),
+            TestMetadata::BooleanPublicKey(BOOLEAN_PUBKEY_TEST),
+            TestMetadata::BooleanPublicKey(BOOLEAN_COMPRESSED_PUBKEY_TEST),
+            TestMetadata::BooleanCiphertext(BOOLEAN_CT_TEST),
+            TestMetadata::BooleanCiphertext(BOOLEAN_COMPRESSED_CT_TEST),
         ]
     }
 }
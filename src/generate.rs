@@ -6,6 +6,7 @@ use std::{
 
 use bincode::Options;
 use serde::Serialize;
+use sha2::{Digest, Sha256};
 use tfhe_0_11_versionable::Versionize as VersionizeTfhe_0_11;
 use tfhe_1_0_versionable::Versionize as VersionizeTfhe_1_0;
 use tfhe_1_1_versionable::Versionize as VersionizeTfhe_1_1;
@@ -13,12 +14,29 @@ use tfhe_versionable::Versionize as VersionizeTfhe_0_10;
 use tfhe_versionable::Versionize as VersionizeTfhe_0_8;
 
 use crate::{
-    data_dir, dir_for_version, TestCompressionParameterSet, TestDistribution, TestMetadata,
-    TestModulusSwitchNoiseReductionParams, TestNoiseSquashingParams, TestParameterSet,
+    dir_for_version, TestBooleanParameterSet, TestCompressionParameterSet, TestDistribution,
+    TestMetadata, TestModulusSwitchNoiseReductionParams, TestNoiseSquashingParams,
+    TestParameterSet,
 };
 
 pub const PRNG_SEED: u128 = 0xdeadbeef;
 
+/// Derives the seed used to generate the data of a test from `PRNG_SEED` and the version, module
+/// and name of the test. This way, adding or reordering tests does not modify the data of the
+/// other ones.
+pub fn test_seed(version: &str, module: &str, test_filename: &str) -> u128 {
+    let mut hasher = Sha256::new();
+    hasher.update(PRNG_SEED.to_le_bytes());
+    for part in [version, module, test_filename] {
+        // Length prefix to avoid collisions between different splits of the same string
+        hasher.update((part.len() as u64).to_le_bytes());
+        hasher.update(part.as_bytes());
+    }
+
+    let hash = hasher.finalize();
+    u128::from_le_bytes(hash[..16].try_into().unwrap())
+}
+
 /// Valid parameter set that can be used in tfhe operations
 pub const VALID_TEST_PARAMS: TestParameterSet = TestParameterSet {
     lwe_dimension: 761,
@@ -146,6 +164,24 @@ pub const VALID_TEST_PARAMS_TUNIFORM_COMPRESSION: TestCompressionParameterSet =
         packing_ks_key_noise_distribution: TestDistribution::TUniform { bound_log2: 42 },
     };
 
+/// Those parameters are insecure and are used to generate small boolean keys
+pub const INSECURE_SMALL_BOOLEAN_TEST_PARAMS: TestBooleanParameterSet = TestBooleanParameterSet {
+    lwe_dimension: 10,
+    glwe_dimension: 2,
+    polynomial_size: 256,
+    lwe_noise_distribution: TestDistribution::Gaussian {
+        stddev: 8.976167396834998e-5,
+    },
+    glwe_noise_distribution: TestDistribution::Gaussian {
+        stddev: 2.989040792967434e-8,
+    },
+    pbs_base_log: 10,
+    pbs_level: 2,
+    ks_base_log: 3,
+    ks_level: 4,
+    encryption_key_choice: Cow::Borrowed("small"),
+};
+
 /// Invalid parameter set to test the limits
 pub const INVALID_TEST_PARAMS: TestParameterSet = TestParameterSet {
     lwe_dimension: usize::MAX,
@@ -239,6 +275,8 @@ macro_rules! define_store_versioned_auxiliary_fn {
 define_store_versioned_auxiliary_fn!(store_versioned_auxiliary_tfhe_0_8, VersionizeTfhe_0_8);
 define_store_versioned_auxiliary_fn!(store_versioned_auxiliary_tfhe_0_10, VersionizeTfhe_0_10);
 define_store_versioned_auxiliary_fn!(store_versioned_auxiliary_tfhe_0_11, VersionizeTfhe_0_11);
+define_store_versioned_auxiliary_fn!(store_versioned_auxiliary_tfhe_1_0, VersionizeTfhe_1_0);
+define_store_versioned_auxiliary_fn!(store_versioned_auxiliary_tfhe_1_1, VersionizeTfhe_1_1);
 
 pub fn store_metadata<Meta: Serialize, P: AsRef<Path>>(value: &Meta, path: P) {
     let serialized = ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default()).unwrap();
@@ -248,21 +286,54 @@ pub fn store_metadata<Meta: Serialize, P: AsRef<Path>>(value: &Meta, path: P) {
 pub trait TfhersVersion {
     const VERSION_NUMBER: &'static str;
 
-    fn data_dir() -> PathBuf {
-        let base_data_dir = data_dir(env!("CARGO_MANIFEST_DIR"));
+    /// The data of a released version of tfhe-rs is used as a reference for backward
+    /// compatibility, so it should never be modified by the generator. This should only be false
+    /// for versions that are not released yet.
+    const FROZEN: bool;
+
+    /// The directory where the data for this version is stored, inside `base_data_dir`
+    fn data_dir(base_data_dir: &Path) -> PathBuf {
         dir_for_version(base_data_dir, Self::VERSION_NUMBER)
     }
 
     /// How to fix the prng seed for this version to make sure the generated testcases do not change every time we run the script
     fn seed_prng(seed: u128);
 
-    /// Generates data for the "shortint" module for this version.
-    /// This should create tfhe-rs shortint types, versionize them and store them into the version specific directory.
-    /// The metadata for the generated tests should be returned in the same order that the tests will be run.
-    fn gen_shortint_data() -> Vec<TestMetadata>;
+    /// Seeds the prng with a seed that only depends on this version and on the module and name of
+    /// the test. This should be called before the generation of each stored object, except in the
+    /// [`Self::legacy_module_generators`].
+    fn seed_prng_for_test(module: &str, test_filename: &str) {
+        Self::seed_prng(test_seed(Self::VERSION_NUMBER, module, test_filename));
+    }
+
+    /// The generators of the data that was stored before the prng was seeded for each test. They
+    /// are run first, in this order, after the prng has been seeded once with [`PRNG_SEED`], and
+    /// should never re-seed it, so that the existing data of frozen versions is generated
+    /// identically. New tests should be added to the [`Self::module_generators`].
+    fn legacy_module_generators() -> Vec<ModuleGenerator> {
+        Vec::new()
+    }
+
+    /// The generators of all the modules that have data for this version. The metadata of each
+    /// module is stored in its own file, named after the module.
+    fn module_generators() -> Vec<ModuleGenerator>;
+}
+
+/// Generates data for a tfhe-rs module (eg: "shortint", "high_level_api",...) for a given version.
+pub struct ModuleGenerator {
+    pub module_name: &'static str,
+    /// This should create tfhe-rs types of the module, versionize them and store them into the
+    /// version specific directory, inside the `base_data_dir` given as parameter.
+    /// The metadata for the generated tests should be returned in the same order that the tests
+    /// will be run.
+    pub gen_data: fn(&Path) -> Vec<TestMetadata>,
+}
 
-    /// Generates data for the "high_level_api" module for this version.
-    /// This should create tfhe-rs HL types, versionize them and store them into the version specific directory.
-    /// The metadata for the generated tests should be returned in the same order that the tests will be run.
-    fn gen_hl_data() -> Vec<TestMetadata>;
+impl ModuleGenerator {
+    pub fn new(module_name: &'static str, gen_data: fn(&Path) -> Vec<TestMetadata>) -> Self {
+        Self {
+            module_name,
+            gen_data,
+        }
+    }
 }
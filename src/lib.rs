@@ -6,9 +6,11 @@ use std::{
 
 #[cfg(feature = "load")]
 use semver::{Prerelease, Version, VersionReq};
+#[cfg(any(feature = "generate", feature = "load"))]
+use sha2::{Digest, Sha256};
 #[cfg(feature = "load")]
 use std::fmt::Display;
-use strum::Display;
+use strum::{Display, VariantNames};
 
 use serde::{Deserialize, Serialize};
 
@@ -26,10 +28,17 @@ pub mod data_1_1;
 pub mod generate;
 #[cfg(feature = "load")]
 pub mod load;
+#[cfg(any(feature = "generate", feature = "load"))]
+pub mod manifest;
+#[cfg(any(feature = "generate", feature = "load"))]
+pub mod validate;
 
 const DATA_DIR: &str = "data";
 
 pub const SHORTINT_MODULE_NAME: &str = "shortint";
+pub const CORE_CRYPTO_MODULE_NAME: &str = "core_crypto";
+pub const BOOLEAN_MODULE_NAME: &str = "boolean";
+pub const INTEGER_MODULE_NAME: &str = "integer";
 pub const HL_MODULE_NAME: &str = "high_level_api";
 pub const ZK_MODULE_NAME: &str = "zk";
 
@@ -97,99 +106,991 @@ pub struct TestCompressionParameterSet {
     pub packing_ks_key_noise_distribution: TestDistribution,
 }
 
+/// This struct re-defines tfhe-rs boolean parameter sets but this allows to be independent of
+/// changes made into the BooleanParameters of tfhe-rs.
+///
+/// The idea here is to define a type that is able to carry the information of the used parameters
+/// without using any tfhe-rs types.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TestBooleanParameterSet {
+    pub lwe_dimension: usize,
+    pub glwe_dimension: usize,
+    pub polynomial_size: usize,
+    pub lwe_noise_distribution: TestDistribution,
+    pub glwe_noise_distribution: TestDistribution,
+    pub pbs_base_log: usize,
+    pub pbs_level: usize,
+    pub ks_base_log: usize,
+    pub ks_level: usize,
+    pub encryption_key_choice: Cow<'static, str>,
+}
+
 /// Representation of a random distribution that is independent from any tfhe-rs version
 #[derive(Serialize, Deserialize, Clone, Debug)]
-pub enum TestDistribution {
-    Gaussian { stddev: f64 },
-    TUniform { bound_log2: u32 },
+pub enum TestDistribution {
+    Gaussian { stddev: f64 },
+    TUniform { bound_log2: u32 },
+}
+
+/// Representation of a clear integer of up to 256 bits that is independent from any tfhe-rs
+/// version.
+///
+/// The value is stored as little endian 64 bits words. Signed values are stored in two's
+/// complement, sign extended to the full 256 bits.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TestWideInteger {
+    pub words: [u64; 4],
+}
+
+impl TestWideInteger {
+    pub const fn from_words(words: [u64; 4]) -> Self {
+        Self { words }
+    }
+
+    pub const fn from_u64(value: u64) -> Self {
+        Self::from_words([value, 0, 0, 0])
+    }
+
+    pub const fn from_u128(value: u128) -> Self {
+        Self::from_words([value as u64, (value >> 64) as u64, 0, 0])
+    }
+
+    pub const fn from_i64(value: i64) -> Self {
+        Self::from_i128(value as i128)
+    }
+
+    pub const fn from_i128(value: i128) -> Self {
+        let extension = if value < 0 { u64::MAX } else { 0 };
+        Self::from_words([value as u64, (value >> 64) as u64, extension, extension])
+    }
+
+    /// The 64 least significant bits of the value
+    pub const fn low_u64(&self) -> u64 {
+        self.words[0]
+    }
+
+    /// The 128 least significant bits of the value
+    pub const fn low_u128(&self) -> u128 {
+        self.words[0] as u128 | (self.words[1] as u128) << 64
+    }
+
+    /// The 128 most significant bits of the value
+    pub const fn high_u128(&self) -> u128 {
+        self.words[2] as u128 | (self.words[3] as u128) << 64
+    }
+}
+
+/// Name of the directory where the data for a given tfhe-rs version is stored
+pub fn version_dir_name(version: &str) -> String {
+    version.replace('.', "_")
+}
+
+/// Name of the file where the metadata of the tests of a given module are stored, at the root of
+/// the data directory
+pub fn metadata_filename(module: &str) -> String {
+    format!("{}.ron", module)
+}
+
+/// Hex encoded sha256 of the little endian bytes of the container of a core_crypto entity. This is
+/// used to check that the decompression of the seeded entities does not change.
+#[cfg(any(feature = "generate", feature = "load"))]
+pub fn container_digest(container: &[u64]) -> String {
+    let mut hasher = Sha256::new();
+    for value in container {
+        hasher.update(value.to_le_bytes());
+    }
+
+    hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+pub fn dir_for_version<P: AsRef<Path>>(data_dir: P, version: &str) -> PathBuf {
+    let mut path = data_dir.as_ref().to_path_buf();
+    path.push(version_dir_name(version));
+
+    path
+}
+
+pub fn data_dir<P: AsRef<Path>>(root: P) -> PathBuf {
+    let mut path = PathBuf::from(root.as_ref());
+    path.push(DATA_DIR);
+
+    path
+}
+
+pub trait TestType {
+    /// The tfhe-rs module where this type reside
+    fn module(&self) -> String;
+
+    /// The Type that is tested
+    fn target_type(&self) -> String;
+
+    /// The name of the file to be tested, without path or extension
+    /// (they will be inferred)
+    fn test_filename(&self) -> String;
+
+    /// The names of the auxiliary files needed by this test (eg: the key used to decrypt a
+    /// ciphertext), in the same directory as the test. If they have no extension, the cbor one will
+    /// be inferred.
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    #[cfg(feature = "load")]
+    fn success(&self, format: load::DataFormat) -> load::TestSuccess {
+        load::TestSuccess {
+            module: self.module(),
+            target_type: self.target_type(),
+            test_filename: self.test_filename(),
+            format,
+        }
+    }
+
+    /// Generic test failure, for errors that are not covered by a more specific constructor
+    #[cfg(feature = "load")]
+    fn failure<E: Display>(&self, error: E, format: load::DataFormat) -> load::TestFailure {
+        self.failure_with_kind(load::FailureKind::Other(format!("{}", error)), format)
+    }
+
+    /// Test failure caused by data that could not be loaded
+    #[cfg(feature = "load")]
+    fn load_failure(&self, error: load::LoadError, format: load::DataFormat) -> load::TestFailure {
+        self.failure_with_kind(load::FailureKind::Load(error), format)
+    }
+
+    /// Test failure caused by loaded data that does not match the metadata
+    #[cfg(feature = "load")]
+    fn value_mismatch<E: Display>(&self, error: E, format: load::DataFormat) -> load::TestFailure {
+        self.failure_with_kind(
+            load::FailureKind::ValueMismatch(format!("{}", error)),
+            format,
+        )
+    }
+
+    #[cfg(feature = "load")]
+    fn failure_with_kind(
+        &self,
+        kind: load::FailureKind,
+        format: load::DataFormat,
+    ) -> load::TestFailure {
+        load::TestFailure {
+            module: self.module(),
+            target_type: self.target_type(),
+            test_filename: self.test_filename(),
+            kind: Box::new(kind),
+            format,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ShortintClientKeyTest {
+    pub test_filename: Cow<'static, str>,
+    pub parameters: TestParameterSet,
+}
+
+impl TestType for ShortintClientKeyTest {
+    fn module(&self) -> String {
+        SHORTINT_MODULE_NAME.to_string()
+    }
+
+    fn target_type(&self) -> String {
+        "ClientKey".to_string()
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ShortintCompressedCiphertextTest {
+    pub test_filename: Cow<'static, str>,
+    pub key_filename: Cow<'static, str>,
+    /// The ciphertext is a `CompressedModulusSwitchedCiphertext` instead of a seeded
+    /// `CompressedCiphertext`
+    pub modulus_switched: bool,
+    pub clear_value: u64,
+}
+
+impl TestType for ShortintCompressedCiphertextTest {
+    fn module(&self) -> String {
+        SHORTINT_MODULE_NAME.to_string()
+    }
+
+    fn target_type(&self) -> String {
+        if self.modulus_switched {
+            "CompressedModulusSwitchedCiphertext".to_string()
+        } else {
+            "CompressedCiphertext".to_string()
+        }
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![self.key_filename.to_string()]
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ShortintCompactCiphertextListTest {
+    pub test_filename: Cow<'static, str>,
+    pub key_filename: Cow<'static, str>,
+    /// Each ciphertext holds two messages, encrypted with the `message_modulus * carry_modulus`
+    /// modulus
+    pub packed: bool,
+    pub clear_values: Cow<'static, [u64]>,
+}
+
+impl TestType for ShortintCompactCiphertextListTest {
+    fn module(&self) -> String {
+        SHORTINT_MODULE_NAME.to_string()
+    }
+
+    fn target_type(&self) -> String {
+        "CompactCiphertextList".to_string()
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![self.key_filename.to_string()]
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ShortintKeySwitchingKeyTest {
+    pub test_filename: Cow<'static, str>,
+    pub input_key_filename: Cow<'static, str>,
+    pub output_key_filename: Cow<'static, str>,
+    pub compressed: bool,
+    pub ks_base_log: usize,
+    pub ks_level: usize,
+    pub destination_key: Cow<'static, str>,
+    /// Value encrypted with the input key, casted and decrypted with the output key
+    pub clear_value: u64,
+}
+
+impl TestType for ShortintKeySwitchingKeyTest {
+    fn module(&self) -> String {
+        SHORTINT_MODULE_NAME.to_string()
+    }
+
+    fn target_type(&self) -> String {
+        "KeySwitchingKey".to_string()
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![
+            self.input_key_filename.to_string(),
+            self.output_key_filename.to_string(),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ShortintServerKeyTest {
+    pub test_filename: Cow<'static, str>,
+    pub client_key_filename: Cow<'static, str>,
+    pub compressed: bool,
+}
+
+impl TestType for ShortintServerKeyTest {
+    fn module(&self) -> String {
+        SHORTINT_MODULE_NAME.to_string()
+    }
+
+    fn target_type(&self) -> String {
+        "ServerKey".to_string()
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![self.client_key_filename.to_string()]
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ShortintPublicKeyTest {
+    pub test_filename: Cow<'static, str>,
+    pub client_key_filename: Cow<'static, str>,
+    pub compressed: bool,
+    pub compact: bool,
+    /// Value encrypted with the public key and decrypted with the client key
+    pub clear_value: u64,
+}
+
+impl TestType for ShortintPublicKeyTest {
+    fn module(&self) -> String {
+        SHORTINT_MODULE_NAME.to_string()
+    }
+
+    fn target_type(&self) -> String {
+        "PublicKey".to_string()
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![self.client_key_filename.to_string()]
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ShortintCiphertextTest {
+    pub test_filename: Cow<'static, str>,
+    pub key_filename: Cow<'static, str>,
+    pub clear_value: u64,
+}
+
+impl TestType for ShortintCiphertextTest {
+    fn module(&self) -> String {
+        SHORTINT_MODULE_NAME.to_string()
+    }
+
+    fn target_type(&self) -> String {
+        "Ciphertext".to_string()
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![self.key_filename.to_string()]
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CoreCryptoLweSecretKeyTest {
+    pub test_filename: Cow<'static, str>,
+    pub lwe_dimension: usize,
+}
+
+impl TestType for CoreCryptoLweSecretKeyTest {
+    fn module(&self) -> String {
+        CORE_CRYPTO_MODULE_NAME.to_string()
+    }
+
+    fn target_type(&self) -> String {
+        "LweSecretKey".to_string()
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CoreCryptoGlweSecretKeyTest {
+    pub test_filename: Cow<'static, str>,
+    pub glwe_dimension: usize,
+    pub polynomial_size: usize,
+}
+
+impl TestType for CoreCryptoGlweSecretKeyTest {
+    fn module(&self) -> String {
+        CORE_CRYPTO_MODULE_NAME.to_string()
+    }
+
+    fn target_type(&self) -> String {
+        "GlweSecretKey".to_string()
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CoreCryptoLweKeyswitchKeyTest {
+    pub test_filename: Cow<'static, str>,
+    pub input_key_filename: Cow<'static, str>,
+    pub output_key_filename: Cow<'static, str>,
+    pub input_lwe_dimension: usize,
+    pub output_lwe_dimension: usize,
+    pub decomp_base_log: usize,
+    pub decomp_level_count: usize,
+    pub ciphertext_modulus: u128,
+    /// Scaling factor applied to the clear value encrypted to check the key
+    pub delta: u64,
+    pub clear_value: u64,
+}
+
+impl TestType for CoreCryptoLweKeyswitchKeyTest {
+    fn module(&self) -> String {
+        CORE_CRYPTO_MODULE_NAME.to_string()
+    }
+
+    fn target_type(&self) -> String {
+        "LweKeyswitchKey".to_string()
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![
+            self.input_key_filename.to_string(),
+            self.output_key_filename.to_string(),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CoreCryptoLweBootstrapKeyTest {
+    pub test_filename: Cow<'static, str>,
+    pub input_key_filename: Cow<'static, str>,
+    pub output_key_filename: Cow<'static, str>,
+    pub input_lwe_dimension: usize,
+    pub glwe_dimension: usize,
+    pub polynomial_size: usize,
+    pub decomp_base_log: usize,
+    pub decomp_level_count: usize,
+    pub ciphertext_modulus: u128,
+    /// The key is stored in the fourier domain (`FourierLweBootstrapKey`)
+    pub fourier: bool,
+    pub delta: u64,
+    pub clear_value: u64,
+}
+
+impl TestType for CoreCryptoLweBootstrapKeyTest {
+    fn module(&self) -> String {
+        CORE_CRYPTO_MODULE_NAME.to_string()
+    }
+
+    fn target_type(&self) -> String {
+        "LweBootstrapKey".to_string()
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![
+            self.input_key_filename.to_string(),
+            self.output_key_filename.to_string(),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CoreCryptoLweMultiBitBootstrapKeyTest {
+    pub test_filename: Cow<'static, str>,
+    pub input_key_filename: Cow<'static, str>,
+    pub output_key_filename: Cow<'static, str>,
+    pub input_lwe_dimension: usize,
+    pub glwe_dimension: usize,
+    pub polynomial_size: usize,
+    pub decomp_base_log: usize,
+    pub decomp_level_count: usize,
+    pub grouping_factor: usize,
+    pub ciphertext_modulus: u128,
+    pub delta: u64,
+    pub clear_value: u64,
+}
+
+impl TestType for CoreCryptoLweMultiBitBootstrapKeyTest {
+    fn module(&self) -> String {
+        CORE_CRYPTO_MODULE_NAME.to_string()
+    }
+
+    fn target_type(&self) -> String {
+        "LweMultiBitBootstrapKey".to_string()
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![
+            self.input_key_filename.to_string(),
+            self.output_key_filename.to_string(),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CoreCryptoLwePackingKeyswitchKeyTest {
+    pub test_filename: Cow<'static, str>,
+    pub input_key_filename: Cow<'static, str>,
+    pub output_key_filename: Cow<'static, str>,
+    pub input_lwe_dimension: usize,
+    pub output_glwe_dimension: usize,
+    pub output_polynomial_size: usize,
+    pub decomp_base_log: usize,
+    pub decomp_level_count: usize,
+    pub ciphertext_modulus: u128,
+    pub delta: u64,
+    pub clear_value: u64,
+}
+
+impl TestType for CoreCryptoLwePackingKeyswitchKeyTest {
+    fn module(&self) -> String {
+        CORE_CRYPTO_MODULE_NAME.to_string()
+    }
+
+    fn target_type(&self) -> String {
+        "LwePackingKeyswitchKey".to_string()
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![
+            self.input_key_filename.to_string(),
+            self.output_key_filename.to_string(),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CoreCryptoLweCompactPublicKeyTest {
+    pub test_filename: Cow<'static, str>,
+    pub key_filename: Cow<'static, str>,
+    pub lwe_dimension: usize,
+    pub ciphertext_modulus: u128,
+    pub delta: u64,
+    pub clear_value: u64,
+}
+
+impl TestType for CoreCryptoLweCompactPublicKeyTest {
+    fn module(&self) -> String {
+        CORE_CRYPTO_MODULE_NAME.to_string()
+    }
+
+    fn target_type(&self) -> String {
+        "LweCompactPublicKey".to_string()
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![self.key_filename.to_string()]
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CoreCryptoLweCiphertextTest {
+    pub test_filename: Cow<'static, str>,
+    pub key_filename: Cow<'static, str>,
+    pub lwe_dimension: usize,
+    pub ciphertext_modulus: u128,
+    /// Scaling factor applied to the clear value to get the encrypted plaintext
+    pub delta: u64,
+    pub clear_value: u64,
+}
+
+impl TestType for CoreCryptoLweCiphertextTest {
+    fn module(&self) -> String {
+        CORE_CRYPTO_MODULE_NAME.to_string()
+    }
+
+    fn target_type(&self) -> String {
+        "LweCiphertext".to_string()
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![self.key_filename.to_string()]
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CoreCryptoLweCiphertextListTest {
+    pub test_filename: Cow<'static, str>,
+    pub key_filename: Cow<'static, str>,
+    pub lwe_dimension: usize,
+    pub ciphertext_modulus: u128,
+    pub delta: u64,
+    pub clear_values: Cow<'static, [u64]>,
+}
+
+impl TestType for CoreCryptoLweCiphertextListTest {
+    fn module(&self) -> String {
+        CORE_CRYPTO_MODULE_NAME.to_string()
+    }
+
+    fn target_type(&self) -> String {
+        "LweCiphertextList".to_string()
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![self.key_filename.to_string()]
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CoreCryptoLweCompactCiphertextListTest {
+    pub test_filename: Cow<'static, str>,
+    pub key_filename: Cow<'static, str>,
+    pub lwe_dimension: usize,
+    pub ciphertext_modulus: u128,
+    pub delta: u64,
+    pub clear_values: Cow<'static, [u64]>,
+}
+
+impl TestType for CoreCryptoLweCompactCiphertextListTest {
+    fn module(&self) -> String {
+        CORE_CRYPTO_MODULE_NAME.to_string()
+    }
+
+    fn target_type(&self) -> String {
+        "LweCompactCiphertextList".to_string()
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![self.key_filename.to_string()]
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CoreCryptoGlweCiphertextTest {
+    pub test_filename: Cow<'static, str>,
+    pub key_filename: Cow<'static, str>,
+    pub glwe_dimension: usize,
+    pub polynomial_size: usize,
+    pub ciphertext_modulus: u128,
+    pub delta: u64,
+    /// The coefficients of the encrypted polynomial, before scaling
+    pub clear_values: Cow<'static, [u64]>,
+}
+
+impl TestType for CoreCryptoGlweCiphertextTest {
+    fn module(&self) -> String {
+        CORE_CRYPTO_MODULE_NAME.to_string()
+    }
+
+    fn target_type(&self) -> String {
+        "GlweCiphertext".to_string()
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![self.key_filename.to_string()]
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CoreCryptoGgswCiphertextTest {
+    pub test_filename: Cow<'static, str>,
+    pub key_filename: Cow<'static, str>,
+    pub glwe_dimension: usize,
+    pub polynomial_size: usize,
+    pub decomp_base_log: usize,
+    pub decomp_level_count: usize,
+    pub ciphertext_modulus: u128,
+    /// The constant encrypted in the ggsw, it is not scaled
+    pub clear_value: u64,
+}
+
+impl TestType for CoreCryptoGgswCiphertextTest {
+    fn module(&self) -> String {
+        CORE_CRYPTO_MODULE_NAME.to_string()
+    }
+
+    fn target_type(&self) -> String {
+        "GgswCiphertext".to_string()
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![self.key_filename.to_string()]
+    }
+}
+
+/// The seeded core_crypto entities, they are stored in their compressed form and expanded by the
+/// driver
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Display)]
+pub enum CoreCryptoSeededEntity {
+    SeededLweCiphertext,
+    SeededGlweCiphertext,
+    SeededLweKeyswitchKey,
+    SeededLweBootstrapKey,
+    SeededLweCompactPublicKey,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CoreCryptoSeededEntityTest {
+    pub test_filename: Cow<'static, str>,
+    pub entity: CoreCryptoSeededEntity,
+    /// Digest of the container of the decompressed entity, computed with [`container_digest`]
+    pub decompressed_digest: Cow<'static, str>,
+}
+
+impl TestType for CoreCryptoSeededEntityTest {
+    fn module(&self) -> String {
+        CORE_CRYPTO_MODULE_NAME.to_string()
+    }
+
+    fn target_type(&self) -> String {
+        self.entity.to_string()
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BooleanClientKeyTest {
+    pub test_filename: Cow<'static, str>,
+    pub parameters: TestBooleanParameterSet,
+}
+
+impl TestType for BooleanClientKeyTest {
+    fn module(&self) -> String {
+        BOOLEAN_MODULE_NAME.to_string()
+    }
+
+    fn target_type(&self) -> String {
+        "ClientKey".to_string()
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BooleanServerKeyTest {
+    pub test_filename: Cow<'static, str>,
+    pub client_key_filename: Cow<'static, str>,
+    pub compressed: bool,
+}
+
+impl TestType for BooleanServerKeyTest {
+    fn module(&self) -> String {
+        BOOLEAN_MODULE_NAME.to_string()
+    }
+
+    fn target_type(&self) -> String {
+        "ServerKey".to_string()
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![self.client_key_filename.to_string()]
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BooleanPublicKeyTest {
+    pub test_filename: Cow<'static, str>,
+    pub client_key_filename: Cow<'static, str>,
+    pub compressed: bool,
+}
+
+impl TestType for BooleanPublicKeyTest {
+    fn module(&self) -> String {
+        BOOLEAN_MODULE_NAME.to_string()
+    }
+
+    fn target_type(&self) -> String {
+        "PublicKey".to_string()
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![self.client_key_filename.to_string()]
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BooleanCiphertextTest {
+    pub test_filename: Cow<'static, str>,
+    pub key_filename: Cow<'static, str>,
+    pub compressed: bool,
+    pub clear_value: bool,
 }
 
-pub fn dir_for_version<P: AsRef<Path>>(data_dir: P, version: &str) -> PathBuf {
-    let mut path = data_dir.as_ref().to_path_buf();
-    path.push(version.replace('.', "_"));
+impl TestType for BooleanCiphertextTest {
+    fn module(&self) -> String {
+        BOOLEAN_MODULE_NAME.to_string()
+    }
 
-    path
+    fn target_type(&self) -> String {
+        "Ciphertext".to_string()
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![self.key_filename.to_string()]
+    }
 }
 
-pub fn data_dir<P: AsRef<Path>>(root: P) -> PathBuf {
-    let mut path = PathBuf::from(root.as_ref());
-    path.push(DATA_DIR);
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct IntegerClientKeyTest {
+    pub test_filename: Cow<'static, str>,
+    pub parameters: TestParameterSet,
+}
 
-    path
+impl TestType for IntegerClientKeyTest {
+    fn module(&self) -> String {
+        INTEGER_MODULE_NAME.to_string()
+    }
+
+    fn target_type(&self) -> String {
+        "ClientKey".to_string()
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
 }
 
-pub trait TestType {
-    /// The tfhe-rs module where this type reside
-    fn module(&self) -> String;
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct IntegerRadixCiphertextTest {
+    pub test_filename: Cow<'static, str>,
+    pub key_filename: Cow<'static, str>,
+    pub num_blocks: usize,
+    pub clear_value: u64,
+}
 
-    /// The Type that is tested
-    fn target_type(&self) -> String;
+impl TestType for IntegerRadixCiphertextTest {
+    fn module(&self) -> String {
+        INTEGER_MODULE_NAME.to_string()
+    }
 
-    /// The name of the file to be tested, without path or extension
-    /// (they will be inferred)
-    fn test_filename(&self) -> String;
+    fn target_type(&self) -> String {
+        "RadixCiphertext".to_string()
+    }
 
-    #[cfg(feature = "load")]
-    fn success(&self, format: load::DataFormat) -> load::TestSuccess {
-        load::TestSuccess {
-            module: self.module(),
-            target_type: self.target_type(),
-            test_filename: self.test_filename(),
-            format,
-        }
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
     }
 
-    #[cfg(feature = "load")]
-    fn failure<E: Display>(&self, error: E, format: load::DataFormat) -> load::TestFailure {
-        load::TestFailure {
-            module: self.module(),
-            target_type: self.target_type(),
-            test_filename: self.test_filename(),
-            source_error: format!("{}", error),
-            format,
-        }
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![self.key_filename.to_string()]
     }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
-pub struct ShortintClientKeyTest {
+pub struct IntegerSignedRadixCiphertextTest {
     pub test_filename: Cow<'static, str>,
-    pub parameters: TestParameterSet,
+    pub key_filename: Cow<'static, str>,
+    pub num_blocks: usize,
+    pub clear_value: i64,
 }
 
-impl TestType for ShortintClientKeyTest {
+impl TestType for IntegerSignedRadixCiphertextTest {
     fn module(&self) -> String {
-        SHORTINT_MODULE_NAME.to_string()
+        INTEGER_MODULE_NAME.to_string()
     }
 
     fn target_type(&self) -> String {
-        "ClientKey".to_string()
+        "SignedRadixCiphertext".to_string()
     }
 
     fn test_filename(&self) -> String {
         self.test_filename.to_string()
     }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![self.key_filename.to_string()]
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
-pub struct ShortintCiphertextTest {
+pub struct IntegerCrtCiphertextTest {
     pub test_filename: Cow<'static, str>,
     pub key_filename: Cow<'static, str>,
+    /// The moduli of the blocks, one block is encrypted for each of them
+    pub crt_bases: Cow<'static, [u64]>,
     pub clear_value: u64,
 }
 
-impl TestType for ShortintCiphertextTest {
+impl TestType for IntegerCrtCiphertextTest {
     fn module(&self) -> String {
-        SHORTINT_MODULE_NAME.to_string()
+        INTEGER_MODULE_NAME.to_string()
     }
 
     fn target_type(&self) -> String {
-        "Ciphertext".to_string()
+        "CrtCiphertext".to_string()
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![self.key_filename.to_string()]
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct IntegerBooleanBlockTest {
+    pub test_filename: Cow<'static, str>,
+    pub key_filename: Cow<'static, str>,
+    pub clear_value: bool,
+}
+
+impl TestType for IntegerBooleanBlockTest {
+    fn module(&self) -> String {
+        INTEGER_MODULE_NAME.to_string()
+    }
+
+    fn target_type(&self) -> String {
+        "BooleanBlock".to_string()
     }
 
     fn test_filename(&self) -> String {
         self.test_filename.to_string()
     }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![self.key_filename.to_string()]
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -231,6 +1132,10 @@ impl TestType for HlServerKeyTest {
     fn test_filename(&self) -> String {
         self.test_filename.to_string()
     }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![self.client_key_filename.to_string()]
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -253,6 +1158,10 @@ impl TestType for HlPublicKeyTest {
     fn test_filename(&self) -> String {
         self.test_filename.to_string()
     }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![self.client_key_filename.to_string()]
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -275,6 +1184,10 @@ impl TestType for HlCiphertextTest {
     fn test_filename(&self) -> String {
         self.test_filename.to_string()
     }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![self.key_filename.to_string()]
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -297,6 +1210,72 @@ impl TestType for HlSignedCiphertextTest {
     fn test_filename(&self) -> String {
         self.test_filename.to_string()
     }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![self.key_filename.to_string()]
+    }
+}
+
+/// A `FheUint` of any width, with a clear value of up to 256 bits.
+///
+/// The existing `FheUint8` tests are described by a [`HlCiphertextTest`], whose format cannot
+/// change.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct HlWideCiphertextTest {
+    pub test_filename: Cow<'static, str>,
+    pub key_filename: Cow<'static, str>,
+    pub compressed: bool,
+    pub num_bits: usize,
+    pub clear_value: TestWideInteger,
+}
+
+impl TestType for HlWideCiphertextTest {
+    fn module(&self) -> String {
+        HL_MODULE_NAME.to_string()
+    }
+
+    fn target_type(&self) -> String {
+        "FheUint".to_string()
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![self.key_filename.to_string()]
+    }
+}
+
+/// A `FheInt` of any width, with a clear value of up to 256 bits.
+///
+/// The existing `FheInt8` tests are described by a [`HlSignedCiphertextTest`], whose format
+/// cannot change.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct HlSignedWideCiphertextTest {
+    pub test_filename: Cow<'static, str>,
+    pub key_filename: Cow<'static, str>,
+    pub compressed: bool,
+    pub num_bits: usize,
+    pub clear_value: TestWideInteger,
+}
+
+impl TestType for HlSignedWideCiphertextTest {
+    fn module(&self) -> String {
+        HL_MODULE_NAME.to_string()
+    }
+
+    fn target_type(&self) -> String {
+        "FheInt".to_string()
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![self.key_filename.to_string()]
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -319,6 +1298,68 @@ impl TestType for HlBoolCiphertextTest {
     fn test_filename(&self) -> String {
         self.test_filename.to_string()
     }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![self.key_filename.to_string()]
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct HlAsciiStringTest {
+    pub test_filename: Cow<'static, str>,
+    pub key_filename: Cow<'static, str>,
+    pub clear_value: Cow<'static, str>,
+    /// Number of encrypted zero characters appended after the clear value
+    pub padding: u32,
+    pub compressed: bool,
+}
+
+impl TestType for HlAsciiStringTest {
+    fn module(&self) -> String {
+        HL_MODULE_NAME.to_string()
+    }
+
+    fn target_type(&self) -> String {
+        "FheAsciiString".to_string()
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![self.key_filename.to_string()]
+    }
+}
+
+/// Representation of a clear value that is independent from any tfhe-rs version.
+///
+/// Integers carry the bit width of the encrypted type they should be decrypted as, e.g. a
+/// `Signed` value with `num_bits: 16` corresponds to a `FheInt16`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum TestClearValue {
+    Bool(bool),
+    Signed {
+        num_bits: usize,
+        value: TestWideInteger,
+    },
+    Unsigned {
+        num_bits: usize,
+        value: TestWideInteger,
+    },
+    String(Cow<'static, str>),
+}
+
+impl TestClearValue {
+    /// The 64 least significant bits of an integer value, or the value of a boolean. Returns
+    /// `None` for a string.
+    pub fn low_u64(&self) -> Option<u64> {
+        match self {
+            Self::Bool(value) => Some(*value as u64),
+            Self::Signed { value, .. } | Self::Unsigned { value, .. } => Some(value.low_u64()),
+            Self::String(_) => None,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Copy, Debug)]
@@ -358,6 +1399,52 @@ impl TestType for HlHeterogeneousCiphertextListTest {
     fn test_filename(&self) -> String {
         self.test_filename.to_string()
     }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        let mut filenames = vec![self.key_filename.to_string()];
+        if let Some(proof_info) = &self.proof_info {
+            filenames.push(proof_info.public_key_filename.to_string());
+            filenames.push(proof_info.params_filename.to_string());
+        }
+        filenames
+    }
+}
+
+/// A heterogeneous list whose clear values carry their type, including the bit width of integers
+/// and strings.
+///
+/// The existing lists are described by a [`HlHeterogeneousCiphertextListTest`], whose format
+/// cannot change.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct HlTypedCiphertextListTest {
+    pub test_filename: Cow<'static, str>,
+    pub key_filename: Cow<'static, str>,
+    pub compressed: bool,
+    pub proof_info: Option<PkeZkProofAuxiliaryInfo>,
+    pub clear_values: Cow<'static, [TestClearValue]>,
+}
+
+impl TestType for HlTypedCiphertextListTest {
+    fn module(&self) -> String {
+        HL_MODULE_NAME.to_string()
+    }
+
+    fn target_type(&self) -> String {
+        "CompactCiphertextList".to_string()
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        let mut filenames = vec![self.key_filename.to_string()];
+        if let Some(proof_info) = &self.proof_info {
+            filenames.push(proof_info.public_key_filename.to_string());
+            filenames.push(proof_info.params_filename.to_string());
+        }
+        filenames
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -379,6 +1466,10 @@ impl TestType for HlSquashedNoiseUnsignedCiphertextTest {
     fn test_filename(&self) -> String {
         self.test_filename.to_string()
     }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![self.key_filename.to_string()]
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -400,6 +1491,10 @@ impl TestType for HlSquashedNoiseSignedCiphertextTest {
     fn test_filename(&self) -> String {
         self.test_filename.to_string()
     }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![self.key_filename.to_string()]
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -421,6 +1516,10 @@ impl TestType for HlSquashedNoiseBoolCiphertextTest {
     fn test_filename(&self) -> String {
         self.test_filename.to_string()
     }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![self.key_filename.to_string()]
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -448,24 +1547,173 @@ impl TestType for ZkPkePublicParamsTest {
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, Display)]
+/// A standalone zk proof of the encryption of a core_crypto compact ciphertext list
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ZkPkeProofTest {
+    pub test_filename: Cow<'static, str>,
+    /// Public params (or crs) used to generate and verify the proof
+    pub params_filename: Cow<'static, str>,
+    /// Lwe compact public key used to encrypt the list
+    pub public_key_filename: Cow<'static, str>,
+    /// Lwe secret key, to decrypt the list
+    pub key_filename: Cow<'static, str>,
+    /// The proven lwe compact ciphertext list
+    pub ciphertext_list_filename: Cow<'static, str>,
+    pub metadata: Cow<'static, str>,
+    pub clear_values: Cow<'static, [u64]>,
+    /// Scaling factor used to encode the clear values
+    pub delta: u64,
+}
+
+impl TestType for ZkPkeProofTest {
+    fn module(&self) -> String {
+        ZK_MODULE_NAME.to_string()
+    }
+
+    fn target_type(&self) -> String {
+        "CompactPkeProof".to_string()
+    }
+
+    fn test_filename(&self) -> String {
+        self.test_filename.to_string()
+    }
+
+    fn auxiliary_filenames(&self) -> Vec<String> {
+        vec![
+            self.params_filename.to_string(),
+            self.public_key_filename.to_string(),
+            self.key_filename.to_string(),
+            self.ciphertext_list_filename.to_string(),
+        ]
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Display, VariantNames)]
 pub enum TestMetadata {
     // Shortint
     ShortintCiphertext(ShortintCiphertextTest),
+    ShortintCompressedCiphertext(ShortintCompressedCiphertextTest),
     ShortintClientKey(ShortintClientKeyTest),
+    ShortintServerKey(ShortintServerKeyTest),
+    ShortintPublicKey(ShortintPublicKeyTest),
+    ShortintCompactCiphertextList(ShortintCompactCiphertextListTest),
+    ShortintKeySwitchingKey(ShortintKeySwitchingKeyTest),
+
+    // Core crypto
+    CoreCryptoLweSecretKey(CoreCryptoLweSecretKeyTest),
+    CoreCryptoGlweSecretKey(CoreCryptoGlweSecretKeyTest),
+    CoreCryptoLweKeyswitchKey(CoreCryptoLweKeyswitchKeyTest),
+    CoreCryptoLweBootstrapKey(CoreCryptoLweBootstrapKeyTest),
+    CoreCryptoLweMultiBitBootstrapKey(CoreCryptoLweMultiBitBootstrapKeyTest),
+    CoreCryptoLwePackingKeyswitchKey(CoreCryptoLwePackingKeyswitchKeyTest),
+    CoreCryptoLweCompactPublicKey(CoreCryptoLweCompactPublicKeyTest),
+    CoreCryptoLweCiphertext(CoreCryptoLweCiphertextTest),
+    CoreCryptoLweCiphertextList(CoreCryptoLweCiphertextListTest),
+    CoreCryptoLweCompactCiphertextList(CoreCryptoLweCompactCiphertextListTest),
+    CoreCryptoGlweCiphertext(CoreCryptoGlweCiphertextTest),
+    CoreCryptoGgswCiphertext(CoreCryptoGgswCiphertextTest),
+    CoreCryptoSeededEntity(CoreCryptoSeededEntityTest),
+
+    // Boolean
+    BooleanClientKey(BooleanClientKeyTest),
+    BooleanServerKey(BooleanServerKeyTest),
+    BooleanPublicKey(BooleanPublicKeyTest),
+    BooleanCiphertext(BooleanCiphertextTest),
+
+    // Integer
+    IntegerClientKey(IntegerClientKeyTest),
+    IntegerRadixCiphertext(IntegerRadixCiphertextTest),
+    IntegerSignedRadixCiphertext(IntegerSignedRadixCiphertextTest),
+    IntegerCrtCiphertext(IntegerCrtCiphertextTest),
+    IntegerBooleanBlock(IntegerBooleanBlockTest),
 
     // Hl
     HlCiphertext(HlCiphertextTest),
     HlSignedCiphertext(HlSignedCiphertextTest),
+    HlWideCiphertext(HlWideCiphertextTest),
+    HlSignedWideCiphertext(HlSignedWideCiphertextTest),
     HlBoolCiphertext(HlBoolCiphertextTest),
+    HlAsciiString(HlAsciiStringTest),
     HlHeterogeneousCiphertextList(HlHeterogeneousCiphertextListTest),
+    HlTypedCiphertextList(HlTypedCiphertextListTest),
     HlClientKey(HlClientKeyTest),
     HlServerKey(HlServerKeyTest),
     HlPublicKey(HlPublicKeyTest),
-    ZkPkePublicParams(ZkPkePublicParamsTest), // We place it in the hl folder since it is currently used with hl tests:
     HlSquashedNoiseUnsignedCiphertext(HlSquashedNoiseUnsignedCiphertextTest),
     HlSquashedNoiseSignedCiphertext(HlSquashedNoiseSignedCiphertextTest),
     HlSquashedNoiseBoolCiphertext(HlSquashedNoiseBoolCiphertextTest),
+
+    // Zk
+    // The public params used by the hl proven lists are also stored in the hl folder, next to
+    // the lists
+    ZkPkePublicParams(ZkPkePublicParamsTest),
+    ZkPkeProof(ZkPkeProofTest),
+}
+
+/// Evaluates `$body` with `$test` bound to the test held by the `TestMetadata`, whatever its
+/// variant
+macro_rules! with_test_type {
+    ($metadata:expr, $test:ident => $body:expr) => {
+        match $metadata {
+            TestMetadata::ShortintCiphertext($test) => $body,
+            TestMetadata::ShortintCompressedCiphertext($test) => $body,
+            TestMetadata::ShortintClientKey($test) => $body,
+            TestMetadata::ShortintServerKey($test) => $body,
+            TestMetadata::ShortintPublicKey($test) => $body,
+            TestMetadata::ShortintCompactCiphertextList($test) => $body,
+            TestMetadata::ShortintKeySwitchingKey($test) => $body,
+            TestMetadata::CoreCryptoLweSecretKey($test) => $body,
+            TestMetadata::CoreCryptoGlweSecretKey($test) => $body,
+            TestMetadata::CoreCryptoLweKeyswitchKey($test) => $body,
+            TestMetadata::CoreCryptoLweBootstrapKey($test) => $body,
+            TestMetadata::CoreCryptoLweMultiBitBootstrapKey($test) => $body,
+            TestMetadata::CoreCryptoLwePackingKeyswitchKey($test) => $body,
+            TestMetadata::CoreCryptoLweCompactPublicKey($test) => $body,
+            TestMetadata::CoreCryptoLweCiphertext($test) => $body,
+            TestMetadata::CoreCryptoLweCiphertextList($test) => $body,
+            TestMetadata::CoreCryptoLweCompactCiphertextList($test) => $body,
+            TestMetadata::CoreCryptoGlweCiphertext($test) => $body,
+            TestMetadata::CoreCryptoGgswCiphertext($test) => $body,
+            TestMetadata::CoreCryptoSeededEntity($test) => $body,
+            TestMetadata::BooleanClientKey($test) => $body,
+            TestMetadata::BooleanServerKey($test) => $body,
+            TestMetadata::BooleanPublicKey($test) => $body,
+            TestMetadata::BooleanCiphertext($test) => $body,
+            TestMetadata::IntegerClientKey($test) => $body,
+            TestMetadata::IntegerRadixCiphertext($test) => $body,
+            TestMetadata::IntegerSignedRadixCiphertext($test) => $body,
+            TestMetadata::IntegerCrtCiphertext($test) => $body,
+            TestMetadata::IntegerBooleanBlock($test) => $body,
+            TestMetadata::HlCiphertext($test) => $body,
+            TestMetadata::HlSignedCiphertext($test) => $body,
+            TestMetadata::HlWideCiphertext($test) => $body,
+            TestMetadata::HlSignedWideCiphertext($test) => $body,
+            TestMetadata::HlBoolCiphertext($test) => $body,
+            TestMetadata::HlAsciiString($test) => $body,
+            TestMetadata::HlHeterogeneousCiphertextList($test) => $body,
+            TestMetadata::HlTypedCiphertextList($test) => $body,
+            TestMetadata::HlClientKey($test) => $body,
+            TestMetadata::HlServerKey($test) => $body,
+            TestMetadata::HlPublicKey($test) => $body,
+            TestMetadata::ZkPkePublicParams($test) => $body,
+            TestMetadata::HlSquashedNoiseUnsignedCiphertext($test) => $body,
+            TestMetadata::HlSquashedNoiseSignedCiphertext($test) => $body,
+            TestMetadata::HlSquashedNoiseBoolCiphertext($test) => $body,
+            TestMetadata::ZkPkeProof($test) => $body,
+        }
+    };
+}
+
+impl TestMetadata {
+    /// The name of the file of this test, without path or extension
+    pub fn test_filename(&self) -> String {
+        with_test_type!(self, test => test.test_filename())
+    }
+
+    /// The names of the auxiliary files needed by this test
+    pub fn auxiliary_filenames(&self) -> Vec<String> {
+        with_test_type!(self, test => test.auxiliary_filenames())
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -1,34 +1,310 @@
 use std::{
-    fmt::Display,
+    error::Error,
+    fmt::{self, Display},
     fs::{self, File},
-    path::Path,
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
 };
 
 use bincode::{DefaultOptions, Options};
-use serde::de::DeserializeOwned;
+use serde::{
+    de::{
+        value::StrDeserializer, DeserializeOwned, EnumAccess, IgnoredAny, IntoDeserializer,
+        VariantAccess, Visitor,
+    },
+    forward_to_deserialize_any, Deserialize, Deserializer,
+};
+use strum::VariantNames;
+
+use crate::{
+    manifest::{FileDigest, Manifest, MANIFEST_FILE},
+    TestMetadata, TestType, Testcase,
+};
+
+/// Errors that can happen while loading the test data or metadata
+#[derive(Debug)]
+pub enum LoadError {
+    /// The file could not be read
+    Io { path: PathBuf, source: io::Error },
+    /// The file could not be decoded from cbor
+    Cbor {
+        path: PathBuf,
+        source: ciborium::de::Error<io::Error>,
+    },
+    /// The file could not be decoded from bincode
+    Bincode {
+        path: PathBuf,
+        source: bincode::Error,
+    },
+    /// The metadata file could not be parsed
+    MetadataParse {
+        path: PathBuf,
+        source: ron::error::SpannedError,
+    },
+    /// The file is a git-lfs pointer, the actual data has not been fetched
+    LfsObjectMissing {
+        path: PathBuf,
+        oid: String,
+        size: u64,
+    },
+    /// The file is not listed in the integrity manifest
+    NotInManifest { path: PathBuf },
+    /// The content of the file does not match the integrity manifest
+    IntegrityMismatch {
+        path: PathBuf,
+        expected: FileDigest,
+        found: FileDigest,
+    },
+}
+
+impl LoadError {
+    /// The path of the file that could not be loaded
+    pub fn path(&self) -> &Path {
+        match self {
+            LoadError::Io { path, .. }
+            | LoadError::Cbor { path, .. }
+            | LoadError::Bincode { path, .. }
+            | LoadError::MetadataParse { path, .. }
+            | LoadError::LfsObjectMissing { path, .. }
+            | LoadError::NotInManifest { path }
+            | LoadError::IntegrityMismatch { path, .. } => path,
+        }
+    }
+
+    /// True if the error comes from test data that is not available locally, and not from an
+    /// actual incompatibility
+    pub fn is_data_not_fetched(&self) -> bool {
+        matches!(self, LoadError::LfsObjectMissing { .. })
+    }
+
+    /// True if the error comes from a data file that does not match the integrity manifest,
+    /// meaning that the data itself has been corrupted or modified.
+    pub fn is_integrity_error(&self) -> bool {
+        matches!(
+            self,
+            LoadError::NotInManifest { .. } | LoadError::IntegrityMismatch { .. }
+        )
+    }
+}
+
+impl Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadError::Io { path, source } => {
+                write!(f, "Failed to read file {}: {}", path.display(), source)
+            }
+            LoadError::Cbor { path, source } => {
+                write!(
+                    f,
+                    "Failed to parse cbor file {}: {}",
+                    path.display(),
+                    source
+                )
+            }
+            LoadError::Bincode { path, source } => {
+                write!(
+                    f,
+                    "Failed to parse bincode file {}: {}",
+                    path.display(),
+                    source
+                )
+            }
+            LoadError::MetadataParse { path, source } => {
+                write!(f, "Invalid test metadata {}: {}", path.display(), source)
+            }
+            LoadError::LfsObjectMissing { path, oid, size } => {
+                write!(
+                    f,
+                    "Data not fetched: {} is a git-lfs pointer to object {} ({} bytes), \
+                    run `git lfs pull` to download it",
+                    path.display(),
+                    oid,
+                    size
+                )
+            }
+            LoadError::NotInManifest { path } => {
+                write!(
+                    f,
+                    "File {} is not listed in the integrity manifest",
+                    path.display()
+                )
+            }
+            LoadError::IntegrityMismatch {
+                path,
+                expected,
+                found,
+            } => {
+                write!(
+                    f,
+                    "Corrupted file {}: expected {}, found {}",
+                    path.display(),
+                    expected,
+                    found
+                )
+            }
+        }
+    }
+}
+
+impl Error for LoadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LoadError::Io { source, .. } => Some(source),
+            LoadError::Cbor { source, .. } => Some(source),
+            LoadError::Bincode { source, .. } => Some(source),
+            LoadError::MetadataParse { source, .. } => Some(source),
+            LoadError::LfsObjectMissing { .. }
+            | LoadError::NotInManifest { .. }
+            | LoadError::IntegrityMismatch { .. } => None,
+        }
+    }
+}
+
+/// First line of a git-lfs pointer file, see https://github.com/git-lfs/git-lfs/blob/main/docs/spec.md
+const LFS_POINTER_HEADER: &[u8] = b"version https://git-lfs.github.com/spec/v1";
+
+/// Parses the object id and size from the content of a git-lfs pointer file
+fn parse_lfs_pointer(content: &str) -> Option<(String, u64)> {
+    let mut oid = None;
+    let mut size = None;
+
+    for line in content.lines() {
+        if let Some(value) = line.strip_prefix("oid ") {
+            oid = Some(value.to_string());
+        } else if let Some(value) = line.strip_prefix("size ") {
+            size = value.parse().ok();
+        }
+    }
+
+    oid.zip(size)
+}
+
+/// Opens a data file, checking that it is not a git-lfs pointer
+fn open_file(path: &Path) -> Result<BufReader<File>, LoadError> {
+    let io_error = |source| LoadError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+
+    let mut reader = BufReader::new(File::open(path).map_err(io_error)?);
+
+    if reader
+        .fill_buf()
+        .map_err(io_error)?
+        .starts_with(LFS_POINTER_HEADER)
+    {
+        let mut content = String::new();
+        // Pointer files are small text files, so anything that fails here is not a pointer and
+        // will be reported by the deserializer.
+        if reader.read_to_string(&mut content).is_ok() {
+            if let Some((oid, size)) = parse_lfs_pointer(&content) {
+                return Err(LoadError::LfsObjectMissing {
+                    path: path.to_path_buf(),
+                    oid,
+                    size,
+                });
+            }
+        }
+        reader = BufReader::new(File::open(path).map_err(io_error)?);
+    }
+
+    Ok(reader)
+}
+
+/// Checks the data files against the integrity manifest written by the generator, to be able to
+/// distinguish a corrupted test corpus from an actual compatibility failure.
+pub struct ManifestChecker {
+    data_dir: PathBuf,
+    manifest: Manifest,
+}
+
+impl ManifestChecker {
+    /// Loads the manifest stored at the root of `data_dir`. The paths of the checked files should
+    /// be inside this `data_dir`, see [`Manifest::get`].
+    pub fn load<P: AsRef<Path>>(data_dir: P) -> Result<Self, LoadError> {
+        let data_dir = data_dir.as_ref().to_path_buf();
+        let manifest = parse_metadata_file(&data_dir.join(MANIFEST_FILE))?;
+
+        Ok(Self { data_dir, manifest })
+    }
 
-use crate::{TestType, Testcase};
+    pub fn manifest(&self) -> &Manifest {
+        &self.manifest
+    }
+
+    /// Checks that the file at `path` matches the manifest
+    pub fn check_file<P: AsRef<Path>>(&self, path: P) -> Result<(), LoadError> {
+        read_file_checked(path.as_ref(), self).map(|_| ())
+    }
+
+    fn check_data(&self, path: &Path, data: &[u8]) -> Result<(), LoadError> {
+        let expected =
+            self.manifest
+                .get(&self.data_dir, path)
+                .ok_or_else(|| LoadError::NotInManifest {
+                    path: path.to_path_buf(),
+                })?;
+
+        let found = FileDigest::from_bytes(data);
+
+        if &found != expected {
+            Err(LoadError::IntegrityMismatch {
+                path: path.to_path_buf(),
+                expected: expected.clone(),
+                found,
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Reads a data file and checks it against the manifest
+fn read_file_checked(path: &Path, checker: &ManifestChecker) -> Result<Vec<u8>, LoadError> {
+    let mut data = Vec::new();
+    open_file(path)?
+        .read_to_end(&mut data)
+        .map_err(|source| LoadError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+    checker.check_data(path, &data)?;
+
+    Ok(data)
+}
+
+fn auxiliary_path(path: &Path) -> PathBuf {
+    match path.extension() {
+        Some(_) => path.to_path_buf(),
+        None => path.with_extension("cbor"),
+    }
+}
 
 /// Loads auxiliary data that might be needed for a test (eg: a key to test a ciphertext)
 /// If the path has an extension the file is loaded as is, if not it adds the .cbor extension
 pub fn load_versioned_auxiliary<Data: DeserializeOwned, P: AsRef<Path>>(
     path: P,
-) -> Result<Data, String> {
-    let path = path.as_ref();
-    let path = match path.extension() {
-        Some(_) => path.to_path_buf(),
-        None => path.with_extension("cbor"),
-    };
+) -> Result<Data, LoadError> {
+    let path = auxiliary_path(path.as_ref());
 
-    let path = path.as_path();
+    let file = open_file(&path)?;
+    DataFormat::Cbor.decode(file, path)
+}
 
-    let file = File::open(path)
-        .map_err(|e| format!("Failed to read auxiliary file {}: {}", path.display(), e))?;
-    ciborium::de::from_reader(file)
-        .map_err(|e| format!("Failed to parse auxiliary file {}: {}", path.display(), e))
+/// Same as [`load_versioned_auxiliary`], but checks the file against the integrity manifest before
+/// deserializing it
+pub fn load_versioned_auxiliary_checked<Data: DeserializeOwned, P: AsRef<Path>>(
+    path: P,
+    checker: &ManifestChecker,
+) -> Result<Data, LoadError> {
+    let path = auxiliary_path(path.as_ref());
+
+    let data = read_file_checked(&path, checker)?;
+    DataFormat::Cbor.decode(data.as_slice(), path)
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum DataFormat {
     Cbor,
     Bincode,
@@ -48,26 +324,56 @@ impl DataFormat {
         }
     }
 
-    /// Loads the file that should be tested
-    pub fn load_versioned_test<Data: DeserializeOwned, P: AsRef<Path>, T: TestType>(
+    fn decode<Data: DeserializeOwned, R: Read>(
         self,
-        dir: P,
-        test: &T,
-    ) -> Result<Data, TestFailure> {
-        let filename = format!("{}.{}", test.test_filename(), self.extension());
-        let file = File::open(dir.as_ref().join(filename))
-            .map_err(|e| test.failure(format!("Failed to read testcase: {}", e), self))?;
-
+        reader: R,
+        path: PathBuf,
+    ) -> Result<Data, LoadError> {
         match self {
-            Self::Cbor => ciborium::de::from_reader(file).map_err(|e| test.failure(e, self)),
+            Self::Cbor => {
+                ciborium::de::from_reader(reader).map_err(|source| LoadError::Cbor { path, source })
+            }
             Self::Bincode => {
                 let options = DefaultOptions::new().with_fixint_encoding();
                 options
-                    .deserialize_from(file)
-                    .map_err(|e| test.failure(e, self))
+                    .deserialize_from(reader)
+                    .map_err(|source| LoadError::Bincode { path, source })
             }
         }
     }
+
+    fn test_path<P: AsRef<Path>, T: TestType>(self, dir: P, test: &T) -> PathBuf {
+        let filename = format!("{}.{}", test.test_filename(), self.extension());
+        dir.as_ref().join(filename)
+    }
+
+    /// Loads the file that should be tested
+    pub fn load_versioned_test<Data: DeserializeOwned, P: AsRef<Path>, T: TestType>(
+        self,
+        dir: P,
+        test: &T,
+    ) -> Result<Data, TestFailure> {
+        let path = self.test_path(dir, test);
+
+        open_file(&path)
+            .and_then(|file| self.decode(file, path))
+            .map_err(|e| test.load_failure(e, self))
+    }
+
+    /// Same as [`Self::load_versioned_test`], but checks the file against the integrity manifest
+    /// before deserializing it
+    pub fn load_versioned_test_checked<Data: DeserializeOwned, P: AsRef<Path>, T: TestType>(
+        self,
+        dir: P,
+        test: &T,
+        checker: &ManifestChecker,
+    ) -> Result<Data, TestFailure> {
+        let path = self.test_path(dir, test);
+
+        read_file_checked(&path, checker)
+            .and_then(|data| self.decode(data.as_slice(), path))
+            .map_err(|e| test.load_failure(e, self))
+    }
 }
 
 pub enum TestResult {
@@ -94,14 +400,92 @@ impl TestResult {
     }
 }
 
+/// The reason why a test failed
+#[derive(Debug)]
+pub enum FailureKind {
+    /// The tested data or the auxiliary data needed by the test could not be loaded
+    Load(LoadError),
+    /// The data has been loaded but does not match the test metadata
+    ValueMismatch(String),
+    /// Any other error reported by the test driver (eg: during unversioning)
+    Other(String),
+}
+
+impl Display for FailureKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FailureKind::Load(error) => write!(f, "{}", error),
+            FailureKind::ValueMismatch(msg) | FailureKind::Other(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl Error for FailureKind {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FailureKind::Load(error) => Some(error),
+            FailureKind::ValueMismatch(_) | FailureKind::Other(_) => None,
+        }
+    }
+}
+
+impl From<LoadError> for FailureKind {
+    fn from(value: LoadError) -> Self {
+        Self::Load(value)
+    }
+}
+
+#[derive(Debug)]
 pub struct TestFailure {
     pub(crate) module: String,
     pub(crate) target_type: String,
     pub(crate) test_filename: String,
-    pub(crate) source_error: String,
+    // Boxed to keep the size of the `Err` variant of the test functions small
+    pub(crate) kind: Box<FailureKind>,
     pub(crate) format: DataFormat,
 }
 
+impl TestFailure {
+    pub fn module(&self) -> &str {
+        &self.module
+    }
+
+    pub fn target_type(&self) -> &str {
+        &self.target_type
+    }
+
+    pub fn test_filename(&self) -> &str {
+        &self.test_filename
+    }
+
+    pub fn format(&self) -> DataFormat {
+        self.format
+    }
+
+    /// The reason of the failure
+    pub fn kind(&self) -> &FailureKind {
+        &self.kind
+    }
+
+    /// True if the test failed because its data has not been fetched from git-lfs. This
+    /// indicates an issue with the local clone of the data and not a compatibility failure.
+    pub fn is_data_not_fetched(&self) -> bool {
+        match self.kind() {
+            FailureKind::Load(error) => error.is_data_not_fetched(),
+            FailureKind::ValueMismatch(_) | FailureKind::Other(_) => false,
+        }
+    }
+
+    /// True if the test failed because its data does not match the integrity manifest. This
+    /// indicates that the test corpus is corrupted and not a compatibility failure.
+    pub fn is_integrity_error(&self) -> bool {
+        match self.kind() {
+            FailureKind::Load(error) => error.is_integrity_error(),
+            FailureKind::ValueMismatch(_) | FailureKind::Other(_) => false,
+        }
+    }
+}
+
 impl Display for TestFailure {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -111,11 +495,18 @@ impl Display for TestFailure {
             self.target_type,
             self.test_filename,
             self.format.extension(),
-            self.source_error
+            self.kind
         )
     }
 }
 
+impl Error for TestFailure {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.kind.source()
+    }
+}
+
+#[derive(Debug)]
 pub struct TestSuccess {
     pub(crate) module: String,
     pub(crate) target_type: String,
@@ -123,6 +514,24 @@ pub struct TestSuccess {
     pub(crate) format: DataFormat,
 }
 
+impl TestSuccess {
+    pub fn module(&self) -> &str {
+        &self.module
+    }
+
+    pub fn target_type(&self) -> &str {
+        &self.target_type
+    }
+
+    pub fn test_filename(&self) -> &str {
+        &self.test_filename
+    }
+
+    pub fn format(&self) -> DataFormat {
+        self.format
+    }
+}
+
 impl Display for TestSuccess {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -136,19 +545,466 @@ impl Display for TestSuccess {
     }
 }
 
+#[derive(Debug)]
 pub struct TestSkipped {
     pub(crate) module: String,
     pub(crate) test_name: String,
 }
 
+impl TestSkipped {
+    pub fn module(&self) -> &str {
+        &self.module
+    }
+
+    pub fn test_name(&self) -> &str {
+        &self.test_name
+    }
+}
+
 impl Display for TestSkipped {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "Test: {}::{}: SKIPPED", self.module, self.test_name)
     }
 }
 
-pub fn load_tests_metadata<P: AsRef<Path>>(path: P) -> Result<Vec<Testcase>, String> {
-    let serialized =
-        fs::read_to_string(path).map_err(|e| format!("Failed to load test metadata: {}", e))?;
-    ron::from_str(&serialized).map_err(|e| format!("Invalid test metadata: {}", e))
+fn parse_metadata_file<Meta: DeserializeOwned>(path: &Path) -> Result<Meta, LoadError> {
+    let serialized = fs::read_to_string(path).map_err(|source| LoadError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    ron::from_str(&serialized).map_err(|source| LoadError::MetadataParse {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+pub fn load_tests_metadata<P: AsRef<Path>>(path: P) -> Result<Vec<Testcase>, LoadError> {
+    parse_metadata_file(path.as_ref())
+}
+
+/// A testcase read from a metadata file by [`load_tests_metadata_lenient`]
+#[allow(clippy::large_enum_variant)]
+pub enum LoadedTestcase {
+    /// A testcase with a test type known by this version of the crate
+    Known(Testcase),
+    /// A testcase with a test type that has been added in a more recent version of the crate.
+    /// It cannot be run and should be skipped.
+    UnknownType(UnknownTestcase),
+}
+
+/// A testcase whose metadata could not be parsed because its test type is unknown
+pub struct UnknownTestcase {
+    pub tfhe_version_min: String,
+    pub tfhe_module: String,
+    pub test_type: String,
+}
+
+impl UnknownTestcase {
+    pub fn skip(&self) -> TestSkipped {
+        TestSkipped {
+            module: self.tfhe_module.to_string(),
+            test_name: self.test_type.to_string(),
+        }
+    }
+}
+
+impl Display for UnknownTestcase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Test: {}::{}: UNKNOWN TEST TYPE",
+            self.tfhe_module, self.test_type
+        )
+    }
+}
+
+#[derive(Deserialize)]
+struct LenientTestcase {
+    tfhe_version_min: String,
+    tfhe_module: String,
+    metadata: LenientMetadata,
+}
+
+impl From<LenientTestcase> for LoadedTestcase {
+    fn from(value: LenientTestcase) -> Self {
+        let LenientTestcase {
+            tfhe_version_min,
+            tfhe_module,
+            metadata,
+        } = value;
+
+        match metadata {
+            LenientMetadata::Known(metadata) => Self::Known(Testcase {
+                tfhe_version_min,
+                tfhe_module,
+                metadata,
+            }),
+            LenientMetadata::Unknown(test_type) => Self::UnknownType(UnknownTestcase {
+                tfhe_version_min,
+                tfhe_module,
+                test_type,
+            }),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for LoadedTestcase {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        LenientTestcase::deserialize(deserializer).map(Self::from)
+    }
+}
+
+/// A [`TestMetadata`] that falls back to the name of the variant if it is unknown, instead of
+/// failing the deserialization.
+#[allow(clippy::large_enum_variant)]
+enum LenientMetadata {
+    Known(TestMetadata),
+    Unknown(String),
+}
+
+impl<'de> Deserialize<'de> for LenientMetadata {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_enum("TestMetadata", TestMetadata::VARIANTS, LenientVisitor)
+    }
+}
+
+struct LenientVisitor;
+
+impl<'de> Visitor<'de> for LenientVisitor {
+    type Value = LenientMetadata;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("enum TestMetadata")
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        let (VariantName(name), variant) = data.variant()?;
+
+        if TestMetadata::VARIANTS.contains(&name.as_str()) {
+            // Hand the variant back to the derived implementation now that we know it can
+            // handle it
+            TestMetadata::deserialize(KnownVariant {
+                name: &name,
+                variant,
+            })
+            .map(LenientMetadata::Known)
+        } else {
+            // All the test types are newtype variants, see `load_tests_metadata_lenient`
+            variant.newtype_variant::<IgnoredAny>()?;
+            Ok(LenientMetadata::Unknown(name))
+        }
+    }
+}
+
+/// The name of an enum variant, read as an identifier
+struct VariantName(String);
+
+impl<'de> Deserialize<'de> for VariantName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct IdentVisitor;
+
+        impl Visitor<'_> for IdentVisitor {
+            type Value = VariantName;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a variant identifier")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(VariantName(v.to_string()))
+            }
+        }
+
+        deserializer.deserialize_identifier(IdentVisitor)
+    }
+}
+
+/// Replays an enum variant whose name has already been read from the input
+struct KnownVariant<'a, A> {
+    name: &'a str,
+    variant: A,
+}
+
+impl<'de, A: VariantAccess<'de>> Deserializer<'de> for KnownVariant<'_, A> {
+    type Error = A::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_enum(self)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+impl<'de, A: VariantAccess<'de>> EnumAccess<'de> for KnownVariant<'_, A> {
+    type Error = A::Error;
+    type Variant = A;
+
+    fn variant_seed<V: serde::de::DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Self::Error> {
+        let name: StrDeserializer<Self::Error> = self.name.into_deserializer();
+        seed.deserialize(name).map(|value| (value, self.variant))
+    }
+}
+
+/// Loads the test metadata, parsing each testcase on its own.
+///
+/// Unlike [`load_tests_metadata`], testcases with a test type that is unknown to this version of
+/// the crate do not prevent the whole file from being loaded. They are returned as
+/// [`LoadedTestcase::UnknownType`] and can be skipped by the test driver.
+///
+/// Since the content of an unknown test type cannot be described, it is skipped as the single
+/// field of a newtype variant, which is the shape of every [`TestMetadata`] variant. Unknown
+/// variants with another shape (unit, tuple or struct variants) still fail the whole file.
+///
+/// The metadata of known test types is parsed as usual, so a testcase of a known type whose
+/// fields have been modified also fails the whole file. This is a change of the metadata scheme
+/// that requires a new version branch of this repository, new fields should instead be added
+/// through a new test type.
+pub fn load_tests_metadata_lenient<P: AsRef<Path>>(
+    path: P,
+) -> Result<Vec<LoadedTestcase>, LoadError> {
+    parse_metadata_file(path.as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+
+    /// Creates an empty directory for a test
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!(
+            "tfhe-backward-compat-data-{}-{}",
+            name,
+            process::id()
+        ));
+        if dir.exists() {
+            fs::remove_dir_all(&dir).unwrap();
+        }
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn testcase(metadata: &str) -> String {
+        format!(
+            "(tfhe_version_min: \"0.8\", tfhe_module: \"high_level_api\", metadata: {metadata})"
+        )
+    }
+
+    fn bool_test(test_filename: &str) -> String {
+        testcase(&format!(
+            "HlBoolCiphertext((test_filename: \"{test_filename}\", key_filename: \"client_key\", \
+            compressed: false, clear_value: true))"
+        ))
+    }
+
+    fn parse_lenient(
+        testcases: &[String],
+    ) -> Result<Vec<LoadedTestcase>, ron::error::SpannedError> {
+        ron::from_str(&format!("[{}]", testcases.join(",")))
+    }
+
+    #[test]
+    fn lenient_loading_skips_unknown_test_types() {
+        let unknown = testcase(
+            "HlFutureCiphertext((test_filename: \"ct\", num_bits: 256, \
+            nested: (values: [1, 2], name: Some(\"x\")), kinds: [Bool, Signed]))",
+        );
+        let testcases = [bool_test("bool1"), unknown, bool_test("bool2")];
+
+        let loaded = parse_lenient(&testcases).unwrap();
+        assert_eq!(loaded.len(), 3);
+
+        match &loaded[0] {
+            LoadedTestcase::Known(test) => assert_eq!(test.metadata.test_filename(), "bool1"),
+            LoadedTestcase::UnknownType(test) => panic!("Unexpected unknown test {test}"),
+        }
+        match &loaded[1] {
+            LoadedTestcase::Known(test) => {
+                panic!("Unexpected known test {}", test.metadata.test_filename())
+            }
+            LoadedTestcase::UnknownType(test) => {
+                assert_eq!(test.test_type, "HlFutureCiphertext");
+                assert_eq!(test.tfhe_module, "high_level_api");
+                assert_eq!(test.tfhe_version_min, "0.8");
+            }
+        }
+        match &loaded[2] {
+            LoadedTestcase::Known(test) => assert_eq!(test.metadata.test_filename(), "bool2"),
+            LoadedTestcase::UnknownType(test) => panic!("Unexpected unknown test {test}"),
+        }
+
+        // The strict loading fails on the same input
+        let strict: Result<Vec<Testcase>, _> = ron::from_str(&format!("[{}]", testcases.join(",")));
+        assert!(strict.is_err());
+    }
+
+    #[test]
+    fn lenient_loading_rejects_unknown_non_newtype_variants() {
+        let testcases = [bool_test("bool1"), testcase("HlFutureCiphertext")];
+
+        assert!(parse_lenient(&testcases).is_err());
+    }
+
+    #[test]
+    fn lenient_loading_rejects_modified_known_test_types() {
+        let modified = testcase(
+            "HlBoolCiphertext((test_filename: \"bool2\", key_filename: \"client_key\", \
+            compressed: false, clear_value: 1))",
+        );
+        let testcases = [bool_test("bool1"), modified];
+
+        assert!(parse_lenient(&testcases).is_err());
+    }
+
+    #[test]
+    fn lenient_loading_from_file() {
+        let dir = test_dir("lenient");
+        let path = dir.join("high_level_api.ron");
+        fs::write(
+            &path,
+            format!("[{}, {}]", bool_test("bool1"), testcase("HlFuture((a: 1))")),
+        )
+        .unwrap();
+
+        let loaded = load_tests_metadata_lenient(&path);
+        fs::remove_dir_all(&dir).unwrap();
+
+        let loaded = loaded.unwrap();
+        assert!(matches!(loaded[0], LoadedTestcase::Known(_)));
+        assert!(matches!(loaded[1], LoadedTestcase::UnknownType(_)));
+    }
+
+    #[test]
+    fn stored_metadata_is_loadable() {
+        let data_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(crate::DATA_DIR);
+
+        let mut metadata_files = 0;
+        for entry in fs::read_dir(&data_dir).unwrap() {
+            let path = entry.unwrap().path();
+            let is_metadata = path.extension().is_some_and(|ext| ext == "ron")
+                && path.file_name().is_some_and(|name| name != MANIFEST_FILE);
+            if !is_metadata {
+                continue;
+            }
+
+            if let Err(err) = load_tests_metadata(&path) {
+                panic!("{}", err);
+            }
+            if let Err(err) = load_tests_metadata_lenient(&path) {
+                panic!("{}", err);
+            }
+            metadata_files += 1;
+        }
+        assert!(
+            metadata_files > 0,
+            "no metadata file in {}",
+            data_dir.display()
+        );
+    }
+
+    #[test]
+    fn lfs_pointers_are_reported_as_missing_data() {
+        let dir = test_dir("lfs");
+        let oid = "sha256:4d7a214614ab2935c943f9e0ff69d22eadbb8f32b1258daaa5e2ca24d17e2393";
+        let pointer = format!(
+            "version https://git-lfs.github.com/spec/v1\noid {}\nsize 12345\n",
+            oid
+        );
+        let pointer_path = dir.join("client_key.cbor");
+        fs::write(&pointer_path, pointer).unwrap();
+        let invalid_path = dir.join("invalid.cbor");
+        fs::write(&invalid_path, b"not a cbor file").unwrap();
+
+        let pointer_result = load_versioned_auxiliary::<u64, _>(&pointer_path);
+        let invalid_result = load_versioned_auxiliary::<u64, _>(&invalid_path);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(pointer_result
+            .as_ref()
+            .is_err_and(|error| error.is_data_not_fetched()));
+        match pointer_result {
+            Err(LoadError::LfsObjectMissing {
+                path,
+                oid: found_oid,
+                size,
+            }) => {
+                assert_eq!(path, pointer_path);
+                assert_eq!(found_oid, oid);
+                assert_eq!(size, 12345);
+            }
+            Err(error) => panic!("Unexpected error: {error}"),
+            Ok(_) => panic!("A git-lfs pointer should not be decoded"),
+        }
+
+        // Other invalid files are still reported as decoding errors
+        assert!(matches!(invalid_result, Err(LoadError::Cbor { .. })));
+    }
+
+    #[test]
+    fn manifest_checker_detects_modified_and_unlisted_files() {
+        let data_dir = test_dir("manifest-checker");
+        let module_dir = data_dir.join("0_8").join("high_level_api");
+        fs::create_dir_all(&module_dir).unwrap();
+
+        let key_path = module_dir.join("client_key.cbor");
+        let ct_path = module_dir.join("ct.cbor");
+        for (path, value) in [(&key_path, 42u64), (&ct_path, 7u64)] {
+            ciborium::ser::into_writer(&value, File::create(path).unwrap()).unwrap();
+        }
+
+        let manifest = Manifest::from_data_dir(&data_dir).unwrap();
+        fs::write(
+            data_dir.join(MANIFEST_FILE),
+            ron::to_string(&manifest).unwrap(),
+        )
+        .unwrap();
+
+        // Files added or modified after the generation of the manifest
+        let unlisted_path = module_dir.join("unlisted.cbor");
+        ciborium::ser::into_writer(&1u64, File::create(&unlisted_path).unwrap()).unwrap();
+        ciborium::ser::into_writer(&8u64, File::create(&ct_path).unwrap()).unwrap();
+
+        let checker = ManifestChecker::load(&data_dir).unwrap();
+        let key =
+            load_versioned_auxiliary_checked::<u64, _>(module_dir.join("client_key"), &checker);
+        let ct = checker.check_file(&ct_path);
+        let unlisted = checker.check_file(&unlisted_path);
+        fs::remove_dir_all(&data_dir).unwrap();
+
+        assert_eq!(key.unwrap(), 42);
+
+        assert!(ct.as_ref().is_err_and(|error| error.is_integrity_error()));
+        match ct {
+            Err(LoadError::IntegrityMismatch {
+                path,
+                expected,
+                found,
+            }) => {
+                assert_eq!(path, ct_path);
+                assert_eq!(
+                    &expected,
+                    manifest.versions["0_8"]
+                        .get("high_level_api/ct.cbor")
+                        .unwrap()
+                );
+                assert_ne!(expected, found);
+            }
+            other => panic!("Unexpected result: {other:?}"),
+        }
+
+        assert!(unlisted
+            .as_ref()
+            .is_err_and(|error| error.is_integrity_error()));
+        assert!(
+            matches!(unlisted, Err(LoadError::NotInManifest { path }) if path == unlisted_path)
+        );
+    }
 }
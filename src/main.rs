@@ -1,4 +1,13 @@
-use std::{fs::remove_dir_all, thread};
+use std::{
+    collections::BTreeMap,
+    env,
+    fmt::{self, Display},
+    fs,
+    path::{Path, PathBuf},
+    process, thread,
+};
+
+use clap::Parser;
 use tfhe_backward_compat_data::{
     data_0_10::V0_10,
     data_0_11::V0_11,
@@ -7,69 +16,713 @@ use tfhe_backward_compat_data::{
     data_1_1::V1_1,
     data_dir,
     generate::{store_metadata, TfhersVersion, PRNG_SEED},
-    Testcase, HL_MODULE_NAME, SHORTINT_MODULE_NAME,
+    manifest::{FileChange, FileChangeKind, Manifest, MANIFEST_FILE},
+    metadata_filename,
+    validate::validate_references,
+    version_dir_name, Testcase,
 };
 
-fn gen_all_data<Vers: TfhersVersion>() -> Vec<Testcase> {
+/// Generates the backward compatibility data for the supported tfhe-rs versions
+#[derive(Parser, Debug)]
+#[command(version, about)]
+struct Args {
+    /// Versions of tfhe-rs to generate data for, all of them if not set
+    #[arg(long, value_delimiter = ',', value_parser = clap::builder::PossibleValuesParser::new(all_versions()))]
+    versions: Vec<String>,
+
+    /// Directory where the data is stored, defaults to the `data` directory of this crate
+    #[arg(long)]
+    output_dir: Option<PathBuf>,
+
+    /// Only regenerate this test, given as `<MODULE>::<FILENAME>` where the filename has no path
+    /// or extension (eg: `shortint::ct1`). Only the generators of its module are run, but the
+    /// legacy generators of a version share the same random generator so all the legacy modules
+    /// that come before it are also generated.
+    #[arg(long, value_parser = parse_test_selector)]
+    test: Option<TestSelector>,
+
+    /// Print the changes in the metadata and the data files without modifying the output
+    /// directory
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Allow the generation to modify the data of frozen versions
+    #[arg(long)]
+    allow_frozen_changes: bool,
+
+    /// Generate the data of each version twice and check that the generated files are identical,
+    /// without modifying the output directory
+    #[arg(long)]
+    check_determinism: bool,
+}
+
+/// A single test, identified by its module and filename
+#[derive(Clone, Debug)]
+struct TestSelector {
+    module: String,
+    test_filename: String,
+}
+
+impl TestSelector {
+    fn matches(&self, test: &Testcase) -> bool {
+        test.tfhe_module == self.module && test.metadata.test_filename() == self.test_filename
+    }
+
+    /// Returns true if `path`, relative to the directory of a version, is one of the files of
+    /// the test
+    fn is_test_file(&self, path: &Path) -> bool {
+        path.parent() == Some(Path::new(&self.module))
+            && path
+                .file_stem()
+                .is_some_and(|stem| stem.to_string_lossy() == self.test_filename)
+    }
+}
+
+impl Display for TestSelector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}::{}", self.module, self.test_filename)
+    }
+}
+
+fn parse_test_selector(value: &str) -> Result<TestSelector, String> {
+    match value.split_once("::") {
+        Some((module, test_filename)) if !module.is_empty() && !test_filename.is_empty() => {
+            Ok(TestSelector {
+                module: module.to_string(),
+                test_filename: test_filename.to_string(),
+            })
+        }
+        _ => Err(format!(
+            "invalid test {value}, expected <MODULE>::<FILENAME> (eg: shortint::ct1)"
+        )),
+    }
+}
+
+/// Entry point of the data generation for a given tfhe-rs version
+struct VersionGenerator {
+    version: &'static str,
+    frozen: bool,
+    gen_data: fn(PathBuf, Option<String>) -> Vec<Testcase>,
+}
+
+impl VersionGenerator {
+    fn new<Vers: TfhersVersion>() -> Self {
+        Self {
+            version: Vers::VERSION_NUMBER,
+            frozen: Vers::FROZEN,
+            gen_data: gen_all_data::<Vers>,
+        }
+    }
+}
+
+fn version_generators() -> [VersionGenerator; 5] {
+    [
+        VersionGenerator::new::<V0_8>(),
+        VersionGenerator::new::<V0_10>(),
+        VersionGenerator::new::<V0_11>(),
+        VersionGenerator::new::<V1_0>(),
+        VersionGenerator::new::<V1_1>(),
+    ]
+}
+
+fn all_versions() -> Vec<&'static str> {
+    version_generators()
+        .iter()
+        .map(|generator| generator.version)
+        .collect()
+}
+
+/// Generates the data of a version. If `module` is set, only the generators of this module are
+/// run, along with the legacy generators that come before it.
+fn gen_all_data<Vers: TfhersVersion>(
+    base_data_dir: PathBuf,
+    module: Option<String>,
+) -> Vec<Testcase> {
     Vers::seed_prng(PRNG_SEED);
 
-    let shortint_tests = Vers::gen_shortint_data();
+    let mut tests = Vec::new();
+
+    let is_selected = |module_name: &str| module.as_ref().is_none_or(|name| name == module_name);
 
-    let mut tests: Vec<Testcase> = shortint_tests
+    // The legacy generators are not seeded independently, so they have to run in order up to the
+    // last one of the selected module to reproduce its data.
+    let legacy_generators = Vers::legacy_module_generators();
+    let legacy_count = legacy_generators
         .iter()
-        .map(|metadata| Testcase {
+        .rposition(|generator| is_selected(generator.module_name))
+        .map_or(0, |idx| idx + 1);
+
+    for module in legacy_generators.into_iter().take(legacy_count).chain(
+        Vers::module_generators()
+            .into_iter()
+            .filter(|generator| is_selected(generator.module_name)),
+    ) {
+        let module_tests = (module.gen_data)(&base_data_dir);
+
+        tests.extend(module_tests.into_iter().map(|metadata| Testcase {
             tfhe_version_min: Vers::VERSION_NUMBER.to_string(),
-            tfhe_module: SHORTINT_MODULE_NAME.to_string(),
-            metadata: metadata.clone(),
+            tfhe_module: module.module_name.to_string(),
+            metadata,
+        }));
+    }
+
+    tests
+}
+
+/// Loads the manifest of the data that is currently stored in `data_dir`
+fn current_manifest(data_dir: &Path) -> Manifest {
+    let manifest_path = data_dir.join(MANIFEST_FILE);
+
+    if manifest_path.exists() {
+        let content = fs::read_to_string(&manifest_path).unwrap();
+        ron::from_str(&content).unwrap()
+    } else if data_dir.exists() {
+        Manifest::from_data_dir(data_dir).unwrap()
+    } else {
+        Manifest::default()
+    }
+}
+
+/// Loads the metadata of all the modules that is currently stored in `data_dir`
+fn current_testcases(data_dir: &Path) -> Vec<Testcase> {
+    let mut testcases = Vec::new();
+
+    if !data_dir.exists() {
+        return testcases;
+    }
+
+    for entry in fs::read_dir(data_dir).unwrap() {
+        let path = entry.unwrap().path();
+        let is_metadata_file = path.is_file()
+            && path.extension().is_some_and(|ext| ext == "ron")
+            && path.file_name().is_some_and(|name| name != MANIFEST_FILE);
+
+        if is_metadata_file {
+            let content = fs::read_to_string(&path).unwrap();
+            let module_testcases: Vec<Testcase> = ron::from_str(&content).unwrap();
+            testcases.extend(module_testcases);
+        }
+    }
+
+    testcases
+}
+
+/// Identifies a testcase in the metadata files
+fn testcase_key(test: &Testcase) -> (String, String, String) {
+    (
+        test.tfhe_module.clone(),
+        test.tfhe_version_min.clone(),
+        test.metadata.test_filename(),
+    )
+}
+
+/// Serializes the metadata of each testcase, indexed by its key
+fn serialize_testcases(tests: &[Testcase]) -> BTreeMap<(String, String, String), String> {
+    tests
+        .iter()
+        .map(|test| (testcase_key(test), ron::to_string(&test.metadata).unwrap()))
+        .collect()
+}
+
+/// Prints the testcases that are added, removed or modified in `new` compared to `old`
+fn print_metadata_diff(old: &[Testcase], new: &[Testcase]) {
+    let old = serialize_testcases(old);
+    let new = serialize_testcases(new);
+
+    println!("Metadata changes:");
+    for (key, metadata) in &new {
+        let (module, version, filename) = key;
+        match old.get(key) {
+            None => println!("  + {module} {version} {filename}: {metadata}"),
+            Some(old_metadata) if old_metadata != metadata => {
+                println!("  - {module} {version} {filename}: {old_metadata}");
+                println!("  + {module} {version} {filename}: {metadata}");
+            }
+            Some(_) => {}
+        }
+    }
+    for (key, metadata) in &old {
+        if !new.contains_key(key) {
+            let (module, version, filename) = key;
+            println!("  - {module} {version} {filename}: {metadata}");
+        }
+    }
+}
+
+/// Checks if a file that has been removed from `old` has been moved to another path of the same
+/// version in `new`, without being modified.
+fn is_moved_file(old: &Manifest, new: &Manifest, change: &FileChange) -> bool {
+    let old_files = &old.versions[&change.version];
+    let digest = &old_files[&change.path];
+
+    new.versions
+        .get(&change.version)
+        .into_iter()
+        .flatten()
+        .any(|(path, new_digest)| new_digest == digest && !old_files.contains_key(path))
+}
+
+/// Lists the changes that modify the existing data of frozen versions. New tests can be added to a
+/// frozen version, but its existing files and metadata entries should not be modified or removed.
+/// They can however be moved to another module of the same version, as long as they are identical.
+fn frozen_violations(
+    old_manifest: &Manifest,
+    new_manifest: &Manifest,
+    old_testcases: &[Testcase],
+    new_testcases: &[Testcase],
+    frozen_dirs: &[String],
+) -> Vec<String> {
+    let mut violations: Vec<String> = old_manifest
+        .diff(new_manifest)
+        .iter()
+        .filter(|change| frozen_dirs.contains(&change.version))
+        .filter(|change| match change.kind {
+            FileChangeKind::Added => false,
+            FileChangeKind::Removed => !is_moved_file(old_manifest, new_manifest, change),
+            FileChangeKind::Modified => true,
         })
+        .map(|change| change.to_string())
         .collect();
 
-    let hl_tests = Vers::gen_hl_data();
+    let old = serialize_testcases(old_testcases);
+    let new = serialize_testcases(new_testcases);
+    // The metadata entry of a test has been moved if an identical entry, with the same filename,
+    // has been added to another module of the same version
+    let is_moved_metadata = |(_, version, filename): &(String, String, String),
+                             metadata: &String| {
+        new.iter().any(|(new_key, new_metadata)| {
+            let (_, new_version, new_filename) = new_key;
+            new_version == version
+                && new_filename == filename
+                && new_metadata == metadata
+                && !old.contains_key(new_key)
+        })
+    };
 
-    tests.extend(hl_tests.iter().map(|metadata| Testcase {
-        tfhe_version_min: Vers::VERSION_NUMBER.to_string(),
-        tfhe_module: HL_MODULE_NAME.to_string(),
-        metadata: metadata.clone(),
-    }));
+    for (key, metadata) in &old {
+        let (module, version, filename) = key;
+        if !frozen_dirs.contains(&version_dir_name(version)) {
+            continue;
+        }
 
-    tests
+        match new.get(key) {
+            None if is_moved_metadata(key, metadata) => {}
+            None => violations.push(format!("{module} {version} {filename} (metadata removed)")),
+            Some(new_metadata) if new_metadata != metadata => {
+                violations.push(format!("{module} {version} {filename} (metadata modified)"))
+            }
+            Some(_) => {}
+        }
+    }
+
+    violations
 }
 
-fn main() {
-    let root_dir = env!("CARGO_MANIFEST_DIR");
-    let data_dir_path = data_dir(root_dir);
-    remove_dir_all(&data_dir_path).unwrap();
+/// Recursively copies the content of `src` into `dst`, without overwriting the files that are
+/// already present in `dst`. The files for which `skip` returns true are not copied.
+fn copy_missing_files(src: &Path, dst: &Path, skip: &dyn Fn(&Path) -> bool) {
+    if !src.exists() {
+        return;
+    }
 
-    let handler_v0_8 = thread::spawn(gen_all_data::<V0_8>);
-    let handler_v0_10 = thread::spawn(gen_all_data::<V0_10>);
-    let handler_v0_11 = thread::spawn(gen_all_data::<V0_11>);
-    let handler_v1_0 = thread::spawn(gen_all_data::<V1_0>);
-    let handler_v1_1 = thread::spawn(gen_all_data::<V1_1>);
+    fs::create_dir_all(dst).unwrap();
+
+    for entry in fs::read_dir(src).unwrap() {
+        let entry = entry.unwrap();
+        let src_path = entry.path();
+        let dst_path = dst.join(entry.file_name());
+
+        if entry.file_type().unwrap().is_dir() {
+            copy_missing_files(&src_path, &dst_path, skip);
+        } else if !dst_path.exists() && !skip(&src_path) {
+            fs::copy(&src_path, &dst_path).unwrap();
+        }
+    }
+}
+
+/// Removes all the files inside the directory of a version that do not belong to the selected test
+fn retain_test_files(version_dir: &Path, selector: &TestSelector) {
+    for file in list_files(version_dir) {
+        if !selector.is_test_file(&file) {
+            fs::remove_file(version_dir.join(file)).unwrap();
+        }
+    }
+}
+
+/// Runs the generation of all the given versions in parallel, in `base_data_dir`, restricted to
+/// `module` if it is set. If the generation panicked for some versions, they are returned once all the threads are done.
+fn run_generators(
+    generators: &[VersionGenerator],
+    base_data_dir: &Path,
+    module: Option<&str>,
+) -> Result<Vec<Testcase>, Vec<&'static str>> {
+    let handlers: Vec<_> = generators
+        .iter()
+        .map(|generator| {
+            let gen_data = generator.gen_data;
+            let base_data_dir = base_data_dir.to_path_buf();
+            let module = module.map(str::to_string);
+            thread::spawn(move || gen_data(base_data_dir, module))
+        })
+        .collect();
 
     let mut testcases = vec![];
+    let mut failed_versions = vec![];
+    for (generator, handler) in generators.iter().zip(handlers) {
+        match handler.join() {
+            Ok(version_testcases) => testcases.extend(version_testcases),
+            Err(_) => failed_versions.push(generator.version),
+        }
+    }
+
+    if failed_versions.is_empty() {
+        Ok(testcases)
+    } else {
+        Err(failed_versions)
+    }
+}
+
+/// Stores the metadata of each module found in `testcases` in its own file inside `data_dir`
+fn store_modules_metadata(testcases: &[Testcase], data_dir: &Path) {
+    let mut modules: BTreeMap<&str, Vec<Testcase>> = BTreeMap::new();
+
+    for test in testcases {
+        modules
+            .entry(&test.tfhe_module)
+            .or_default()
+            .push(test.clone());
+    }
+
+    for (module, module_testcases) in modules {
+        store_metadata(&module_testcases, data_dir.join(metadata_filename(module)));
+    }
+}
+
+/// Lists the files inside `dir`, with their path relative to `dir`, in a deterministic order
+fn list_files(dir: &Path) -> Vec<PathBuf> {
+    fn list_files_rec(dir: &Path, prefix: &Path, files: &mut Vec<PathBuf>) {
+        for entry in fs::read_dir(dir).unwrap() {
+            let entry = entry.unwrap();
+            let relative = prefix.join(entry.file_name());
+
+            if entry.file_type().unwrap().is_dir() {
+                list_files_rec(&entry.path(), &relative, files);
+            } else {
+                files.push(relative);
+            }
+        }
+    }
+
+    let mut files = Vec::new();
+    if dir.exists() {
+        list_files_rec(dir, Path::new(""), &mut files);
+    }
+    files.sort();
+    files
+}
+
+/// Compares the content of two generated data directories, and returns a description of the first
+/// difference that is found
+fn first_divergence(dir_a: &Path, dir_b: &Path) -> Option<String> {
+    let files_a = list_files(dir_a);
+    let files_b = list_files(dir_b);
+
+    if let Some(file) = files_a.iter().find(|file| !files_b.contains(file)) {
+        return Some(format!(
+            "{} is only present in the first run",
+            file.display()
+        ));
+    }
+    if let Some(file) = files_b.iter().find(|file| !files_a.contains(file)) {
+        return Some(format!(
+            "{} is only present in the second run",
+            file.display()
+        ));
+    }
+
+    for file in files_a {
+        let content_a = fs::read(dir_a.join(&file)).unwrap();
+        let content_b = fs::read(dir_b.join(&file)).unwrap();
+
+        let offset = content_a
+            .iter()
+            .zip(content_b.iter())
+            .position(|(byte_a, byte_b)| byte_a != byte_b)
+            .or_else(|| {
+                (content_a.len() != content_b.len()).then_some(content_a.len().min(content_b.len()))
+            });
+
+        if let Some(offset) = offset {
+            let test = file.file_stem().unwrap_or_default().to_string_lossy();
+            return Some(format!(
+                "test {} diverges in {} at byte offset {}",
+                test,
+                file.display(),
+                offset
+            ));
+        }
+    }
+
+    None
+}
+
+/// Runs the generation of each version twice in separate temporary directories and checks that
+/// the generated files are identical. Exits with an error if it is not the case.
+fn check_determinism(generators: &[VersionGenerator]) {
+    let check_dir = env::temp_dir().join("tfhe-backward-compat-data-determinism");
+    if check_dir.exists() {
+        fs::remove_dir_all(&check_dir).unwrap();
+    }
+
+    let mut diverging = false;
+
+    for generator in generators {
+        let runs = ["run_1", "run_2"].map(|run| check_dir.join(generator.version).join(run));
+
+        for run_dir in &runs {
+            fs::create_dir_all(run_dir).unwrap();
+            let testcases = match run_generators(std::slice::from_ref(generator), run_dir, None) {
+                Ok(testcases) => testcases,
+                Err(_) => {
+                    eprintln!(
+                        "Error: the generation failed for version {}",
+                        generator.version
+                    );
+                    abort(&check_dir);
+                }
+            };
+            store_modules_metadata(&testcases, run_dir);
+        }
+
+        match first_divergence(&runs[0], &runs[1]) {
+            Some(divergence) => {
+                diverging = true;
+                println!(
+                    "Version {}: NOT DETERMINISTIC: {}",
+                    generator.version, divergence
+                );
+            }
+            None => println!("Version {}: OK", generator.version),
+        }
+    }
+
+    fs::remove_dir_all(&check_dir).unwrap();
+
+    if diverging {
+        process::exit(1);
+    }
+}
+
+/// Discards the generated data and exits with an error
+fn abort(scratch_dir: &Path) -> ! {
+    if scratch_dir.exists() {
+        fs::remove_dir_all(scratch_dir).unwrap();
+    }
+    process::exit(1);
+}
 
-    testcases.extend_from_slice(&handler_v0_8.join().unwrap());
-    testcases.extend_from_slice(&handler_v0_10.join().unwrap());
-    testcases.extend_from_slice(&handler_v0_11.join().unwrap());
-    testcases.extend_from_slice(&handler_v1_0.join().unwrap());
-    testcases.extend_from_slice(&handler_v1_1.join().unwrap());
+/// Moves the generated data in place of the current data. The current data is first moved to a
+/// backup directory, that is restored if the generated data cannot be moved.
+fn replace_data_dir(scratch_dir: &Path, data_dir: &Path) {
+    let backup_dir = data_dir.with_extension("old");
+    if backup_dir.exists() {
+        fs::remove_dir_all(&backup_dir).unwrap();
+    }
 
-    let shortint_testcases: Vec<Testcase> = testcases
+    if data_dir.exists() {
+        fs::rename(data_dir, &backup_dir).unwrap();
+    }
+
+    if let Err(err) = fs::rename(scratch_dir, data_dir) {
+        if backup_dir.exists() {
+            fs::rename(&backup_dir, data_dir).unwrap();
+        }
+        panic!(
+            "Failed to move {} to {}: {}",
+            scratch_dir.display(),
+            data_dir.display(),
+            err
+        );
+    }
+
+    if backup_dir.exists() {
+        fs::remove_dir_all(&backup_dir).unwrap();
+    }
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let data_dir_path = args
+        .output_dir
+        .clone()
+        .unwrap_or_else(|| data_dir(env!("CARGO_MANIFEST_DIR")));
+
+    // The data is first generated in a scratch directory, and only moved to the real data
+    // directory once we have checked that it does not modify released versions. During a dry
+    // run, the scratch directory is created in the system temp dir so the output directory is
+    // left untouched.
+    let scratch_dir_path = if args.dry_run {
+        env::temp_dir().join("tfhe-backward-compat-data-dry-run")
+    } else {
+        data_dir_path.with_extension("tmp")
+    };
+    if scratch_dir_path.exists() {
+        fs::remove_dir_all(&scratch_dir_path).unwrap();
+    }
+
+    let generators: Vec<VersionGenerator> = version_generators()
+        .into_iter()
+        .filter(|generator| {
+            args.versions.is_empty() || args.versions.iter().any(|v| v == generator.version)
+        })
+        .collect();
+
+    if args.check_determinism {
+        check_determinism(&generators);
+        return;
+    }
+
+    fs::create_dir_all(&scratch_dir_path).unwrap();
+
+    // The scratch directory is discarded if the generation of a version failed, so the current
+    // data is left untouched.
+    let module = args.test.as_ref().map(|selector| selector.module.as_str());
+    let mut generated_testcases = match run_generators(&generators, &scratch_dir_path, module) {
+        Ok(testcases) => testcases,
+        Err(failed_versions) => {
+            eprintln!(
+                "Error: the generation failed for versions: {}",
+                failed_versions.join(", ")
+            );
+            abort(&scratch_dir_path);
+        }
+    };
+
+    if let Some(selector) = &args.test {
+        generated_testcases.retain(|test| selector.matches(test));
+        if generated_testcases.is_empty() {
+            eprintln!("Error: no test named {} in the selected versions", selector);
+            abort(&scratch_dir_path);
+        }
+    }
+
+    // Versions that are not regenerated (and other tests of the regenerated versions when a
+    // single test is selected) keep their current data and metadata.
+    let generated_dirs: Vec<String> = generators
         .iter()
-        .filter(|test| test.tfhe_module == SHORTINT_MODULE_NAME)
-        .cloned()
+        .map(|generator| version_dir_name(generator.version))
         .collect();
+    let is_regenerated = |test: &Testcase| {
+        generated_dirs.contains(&version_dir_name(&test.tfhe_version_min))
+            && args
+                .test
+                .as_ref()
+                .is_none_or(|selector| selector.matches(test))
+    };
 
-    store_metadata(&shortint_testcases, data_dir_path.join("shortint.ron"));
+    // A regenerated test keeps the position of its current entry in the metadata, only new tests
+    // are appended.
+    let old_testcases = current_testcases(&data_dir_path);
+    let mut testcases: Vec<Testcase> = old_testcases
+        .iter()
+        .filter_map(|test| {
+            if !is_regenerated(test) {
+                return Some(test.clone());
+            }
+            let key = testcase_key(test);
+            let idx = generated_testcases
+                .iter()
+                .position(|generated| testcase_key(generated) == key)?;
+            Some(generated_testcases.remove(idx))
+        })
+        .collect();
+    testcases.extend(generated_testcases);
+
+    if let Some(selector) = &args.test {
+        for dir in &generated_dirs {
+            let scratch_version_dir = scratch_dir_path.join(dir);
+            let current_version_dir = data_dir_path.join(dir);
+
+            if scratch_version_dir.exists() {
+                retain_test_files(&scratch_version_dir, selector);
+            }
+            copy_missing_files(&current_version_dir, &scratch_version_dir, &|path| {
+                path.strip_prefix(&current_version_dir)
+                    .is_ok_and(|relative| selector.is_test_file(relative))
+            });
+        }
+    }
+
+    if data_dir_path.exists() {
+        for entry in fs::read_dir(&data_dir_path).unwrap() {
+            let entry = entry.unwrap();
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if entry.file_type().unwrap().is_dir() && !generated_dirs.contains(&name) {
+                copy_missing_files(&entry.path(), &scratch_dir_path.join(&name), &|_| false);
+            }
+        }
+    }
+
+    store_modules_metadata(&testcases, &scratch_dir_path);
+
+    let issues = validate_references(&scratch_dir_path, &testcases).unwrap();
+    if !issues.is_empty() {
+        eprintln!("Error: the metadata does not match the generated files:");
+        for issue in &issues {
+            eprintln!("  {}", issue);
+        }
+        abort(&scratch_dir_path);
+    }
+
+    let manifest = Manifest::from_data_dir(&scratch_dir_path).unwrap();
+    store_metadata(&manifest, scratch_dir_path.join(MANIFEST_FILE));
 
-    let high_level_api_testcases: Vec<Testcase> = testcases
+    let old_manifest = current_manifest(&data_dir_path);
+    let changes = old_manifest.diff(&manifest);
+
+    if args.dry_run {
+        print_metadata_diff(&old_testcases, &testcases);
+    }
+
+    if !changes.is_empty() {
+        println!("Changed files:");
+        for change in &changes {
+            println!("  {}", change);
+        }
+    }
+
+    if args.dry_run {
+        fs::remove_dir_all(&scratch_dir_path).unwrap();
+        return;
+    }
+
+    let frozen_dirs: Vec<String> = version_generators()
         .iter()
-        .filter(|test| test.tfhe_module == HL_MODULE_NAME)
-        .cloned()
+        .filter(|generator| generator.frozen)
+        .map(|generator| version_dir_name(generator.version))
         .collect();
 
-    store_metadata(
-        &high_level_api_testcases,
-        data_dir_path.join("high_level_api.ron"),
+    let violations = frozen_violations(
+        &old_manifest,
+        &manifest,
+        &old_testcases,
+        &testcases,
+        &frozen_dirs,
     );
+
+    if !violations.is_empty() && !args.allow_frozen_changes {
+        eprintln!(
+            "Error: the generation modified the data of frozen versions, the previous data \
+has been kept:"
+        );
+        for violation in violations {
+            eprintln!("  {}", violation);
+        }
+        eprintln!("If this is intended, run the generation again with --allow-frozen-changes");
+
+        abort(&scratch_dir_path);
+    }
+
+    replace_data_dir(&scratch_dir_path, &data_dir_path);
 }
@@ -0,0 +1,305 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+    fs, io,
+    path::{Component, Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Name of the manifest file, stored at the root of the data directory
+pub const MANIFEST_FILE: &str = "manifest.ron";
+
+/// Hash and size of a data file
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct FileDigest {
+    pub sha256: String,
+    pub size: u64,
+}
+
+impl FileDigest {
+    pub fn from_bytes(data: &[u8]) -> Self {
+        let hash = Sha256::digest(data);
+
+        Self {
+            sha256: hash.iter().map(|byte| format!("{:02x}", byte)).collect(),
+            size: data.len() as u64,
+        }
+    }
+}
+
+impl Display for FileDigest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "sha256:{} ({} bytes)", self.sha256, self.size)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileChangeKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+/// A file that differs between two manifests
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileChange {
+    pub version: String,
+    pub path: String,
+    pub kind: FileChangeKind,
+}
+
+impl Display for FileChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = match self.kind {
+            FileChangeKind::Added => "added",
+            FileChangeKind::Removed => "removed",
+            FileChangeKind::Modified => "modified",
+        };
+
+        write!(f, "{}/{} ({})", self.version, self.path, kind)
+    }
+}
+
+/// Lists the digest of every file in the data directory, to be able to detect corrupted or
+/// modified files.
+///
+/// Files are indexed by the name of their version directory (eg: "0_8") and then by their path
+/// inside this directory (eg: "shortint/ct1.cbor").
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Manifest {
+    pub versions: BTreeMap<String, BTreeMap<String, FileDigest>>,
+}
+
+impl Manifest {
+    /// Builds the manifest by hashing all the files found in the version directories of
+    /// `data_dir`
+    pub fn from_data_dir<P: AsRef<Path>>(data_dir: P) -> io::Result<Self> {
+        let mut versions = BTreeMap::new();
+
+        for entry in fs::read_dir(data_dir)? {
+            let entry = entry?;
+            if entry.file_type()?.is_dir() {
+                let mut files = BTreeMap::new();
+                hash_dir_content(&entry.path(), "", &mut files)?;
+                versions.insert(entry.file_name().to_string_lossy().into_owned(), files);
+            }
+        }
+
+        Ok(Self { versions })
+    }
+
+    /// Returns the digest of the file at `path`, that should be inside `data_dir`.
+    ///
+    /// Both paths are canonicalized when they exist, so they can be given in different forms (eg:
+    /// relative and absolute).
+    pub fn get<P: AsRef<Path>, Q: AsRef<Path>>(&self, data_dir: P, path: Q) -> Option<&FileDigest> {
+        let data_dir = canonicalize_if_exists(data_dir.as_ref());
+        let path = canonicalize_if_exists(path.as_ref());
+        let relative = path.strip_prefix(data_dir).ok()?;
+        let mut components = relative.components().map(|component| match component {
+            Component::Normal(name) => Some(name.to_string_lossy()),
+            _ => None,
+        });
+
+        let version = components.next()??;
+        let file_path = components.collect::<Option<Vec<_>>>()?.join("/");
+
+        self.versions.get(version.as_ref())?.get(&file_path)
+    }
+
+    /// Lists the files that have been added, removed or modified in `new` compared to `self`
+    pub fn diff(&self, new: &Manifest) -> Vec<FileChange> {
+        let empty = BTreeMap::new();
+        let versions: BTreeSet<&String> = self.versions.keys().chain(new.versions.keys()).collect();
+
+        let mut changes = Vec::new();
+
+        for version in versions {
+            let old_files = self.versions.get(version).unwrap_or(&empty);
+            let new_files = new.versions.get(version).unwrap_or(&empty);
+            let paths: BTreeSet<&String> = old_files.keys().chain(new_files.keys()).collect();
+
+            for path in paths {
+                let kind = match (old_files.get(path), new_files.get(path)) {
+                    (None, Some(_)) => FileChangeKind::Added,
+                    (Some(_), None) => FileChangeKind::Removed,
+                    (Some(old), Some(new)) if old != new => FileChangeKind::Modified,
+                    _ => continue,
+                };
+
+                changes.push(FileChange {
+                    version: version.clone(),
+                    path: path.clone(),
+                    kind,
+                });
+            }
+        }
+
+        changes
+    }
+}
+
+fn canonicalize_if_exists(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+fn hash_dir_content(
+    dir: &Path,
+    prefix: &str,
+    files: &mut BTreeMap<String, FileDigest>,
+) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = format!("{}{}", prefix, entry.file_name().to_string_lossy());
+
+        if entry.file_type()?.is_dir() {
+            hash_dir_content(&entry.path(), &format!("{}/", name), files)?;
+        } else {
+            files.insert(name, FileDigest::from_bytes(&fs::read(entry.path())?));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+
+    fn digest(content: &str) -> FileDigest {
+        FileDigest::from_bytes(content.as_bytes())
+    }
+
+    fn manifest(versions: &[(&str, &[(&str, &str)])]) -> Manifest {
+        Manifest {
+            versions: versions
+                .iter()
+                .map(|(version, files)| {
+                    let files = files
+                        .iter()
+                        .map(|(path, content)| (path.to_string(), digest(content)))
+                        .collect();
+                    (version.to_string(), files)
+                })
+                .collect(),
+        }
+    }
+
+    fn change(version: &str, path: &str, kind: FileChangeKind) -> FileChange {
+        FileChange {
+            version: version.to_string(),
+            path: path.to_string(),
+            kind,
+        }
+    }
+
+    #[test]
+    fn diff_lists_added_removed_and_modified_files() {
+        let old = manifest(&[
+            (
+                "0_8",
+                &[
+                    ("shortint/ct1.cbor", "ct1"),
+                    ("shortint/ct2.cbor", "ct2"),
+                    ("shortint/client_key.cbor", "key"),
+                ],
+            ),
+            ("0_10", &[("high_level_api/client_key.cbor", "key")]),
+        ]);
+        let new = manifest(&[
+            (
+                "0_8",
+                &[
+                    ("shortint/ct1.cbor", "ct1"),
+                    ("shortint/ct2.cbor", "modified ct2"),
+                    ("shortint/ct3.cbor", "ct3"),
+                ],
+            ),
+            ("1_0", &[("high_level_api/client_key.cbor", "key")]),
+        ]);
+
+        assert_eq!(
+            old.diff(&new),
+            vec![
+                change(
+                    "0_10",
+                    "high_level_api/client_key.cbor",
+                    FileChangeKind::Removed
+                ),
+                change("0_8", "shortint/client_key.cbor", FileChangeKind::Removed),
+                change("0_8", "shortint/ct2.cbor", FileChangeKind::Modified),
+                change("0_8", "shortint/ct3.cbor", FileChangeKind::Added),
+                change(
+                    "1_0",
+                    "high_level_api/client_key.cbor",
+                    FileChangeKind::Added
+                ),
+            ]
+        );
+        assert!(new.diff(&new).is_empty());
+    }
+
+    /// Same path as `path`, but relative to the current directory
+    fn relative_to_current_dir(path: &Path) -> PathBuf {
+        let current_dir = env::current_dir().unwrap();
+        let normal_components = |path: &Path| {
+            path.components()
+                .filter(|component| matches!(component, Component::Normal(_)))
+                .map(|component| component.as_os_str().to_os_string())
+                .collect::<Vec<_>>()
+        };
+
+        let mut relative = PathBuf::new();
+        for _ in normal_components(&current_dir) {
+            relative.push("..");
+        }
+        relative.extend(normal_components(path));
+        relative
+    }
+
+    #[test]
+    fn get_resolves_the_path_of_files() {
+        let data_dir = env::temp_dir().join(format!(
+            "tfhe-backward-compat-data-manifest-{}",
+            process::id()
+        ));
+        let module_dir = data_dir.join("0_8").join("shortint");
+        fs::create_dir_all(&module_dir).unwrap();
+        fs::write(module_dir.join("ct1.cbor"), "ct1").unwrap();
+        fs::write(data_dir.join("shortint.ron"), "[]").unwrap();
+
+        let manifest = Manifest::from_data_dir(&data_dir).unwrap();
+        let expected = digest("ct1");
+        let absolute = module_dir.join("ct1.cbor");
+        let relative = relative_to_current_dir(&absolute);
+        let not_normalized = module_dir.join("..").join("shortint").join("ct1.cbor");
+
+        let found_absolute = manifest.get(&data_dir, &absolute).cloned();
+        let found_relative = manifest.get(&data_dir, &relative).cloned();
+        let found_relative_dir = manifest
+            .get(relative_to_current_dir(&data_dir), &absolute)
+            .cloned();
+        let found_not_normalized = manifest.get(&data_dir, &not_normalized).cloned();
+        let found_missing = manifest
+            .get(&data_dir, module_dir.join("ct2.cbor"))
+            .cloned();
+        let found_outside = manifest.get(&module_dir, &absolute).cloned();
+
+        fs::remove_dir_all(&data_dir).unwrap();
+
+        // Only the files of the version directories are listed
+        assert_eq!(manifest.versions.len(), 1);
+        assert_eq!(manifest.versions["0_8"].len(), 1);
+
+        assert_eq!(found_absolute.as_ref(), Some(&expected));
+        assert_eq!(found_relative.as_ref(), Some(&expected));
+        assert_eq!(found_relative_dir.as_ref(), Some(&expected));
+        assert_eq!(found_not_normalized.as_ref(), Some(&expected));
+        assert_eq!(found_missing, None);
+        assert_eq!(found_outside, None);
+    }
+}
@@ -0,0 +1,264 @@
+use std::{
+    collections::BTreeSet,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{dir_for_version, Testcase};
+
+/// Extensions of the two formats in which the test files are stored
+const TEST_FILE_EXTENSIONS: [&str; 2] = ["cbor", "bcode"];
+
+/// Extension of the auxiliary files, when it is not specified in the metadata
+const AUXILIARY_FILE_EXTENSION: &str = "cbor";
+
+/// An inconsistency between the metadata and the files stored in the data directory
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReferenceIssue {
+    /// A file referenced by a test does not exist
+    MissingFile { test: String, path: PathBuf },
+    /// A test file exists in one format but not in the other one
+    MissingFormat { test: String, path: PathBuf },
+    /// A file of the data directory is not referenced by any test
+    UnreferencedFile { path: PathBuf },
+}
+
+impl Display for ReferenceIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReferenceIssue::MissingFile { test, path } => {
+                write!(f, "{} references missing file {}", test, path.display())
+            }
+            ReferenceIssue::MissingFormat { test, path } => {
+                write!(f, "{} is missing its format pair {}", test, path.display())
+            }
+            ReferenceIssue::UnreferencedFile { path } => {
+                write!(f, "{} is not referenced by any test", path.display())
+            }
+        }
+    }
+}
+
+/// Returns the path of an auxiliary file referenced by a test stored in `dir`. If the filename has
+/// no extension, the cbor one is used.
+fn auxiliary_path(dir: &Path, filename: &str) -> PathBuf {
+    let path = dir.join(filename);
+
+    match path.extension() {
+        Some(_) => path,
+        None => path.with_extension(AUXILIARY_FILE_EXTENSION),
+    }
+}
+
+/// Checks that the files referenced by `testcases` are present in `data_dir` and that all the
+/// files stored in the version directories of `data_dir` are used by at least one test.
+///
+/// Every test file should be present in both formats. The auxiliary files (keys, public params,...)
+/// are only stored in cbor.
+pub fn validate_references<P: AsRef<Path>>(
+    data_dir: P,
+    testcases: &[Testcase],
+) -> io::Result<Vec<ReferenceIssue>> {
+    let data_dir = data_dir.as_ref();
+    let mut issues = Vec::new();
+    let mut referenced = BTreeSet::new();
+
+    for testcase in testcases {
+        let dir = dir_for_version(data_dir, &testcase.tfhe_version_min).join(&testcase.tfhe_module);
+        let test_filename = testcase.metadata.test_filename();
+        let test = format!("{}::{}", testcase.tfhe_module, test_filename);
+
+        let test_paths = TEST_FILE_EXTENSIONS.map(|ext| dir.join(format!("{test_filename}.{ext}")));
+        let missing: Vec<&PathBuf> = test_paths.iter().filter(|path| !path.exists()).collect();
+
+        for path in &missing {
+            let issue = if missing.len() == test_paths.len() {
+                ReferenceIssue::MissingFile {
+                    test: test.clone(),
+                    path: path.to_path_buf(),
+                }
+            } else {
+                ReferenceIssue::MissingFormat {
+                    test: test.clone(),
+                    path: path.to_path_buf(),
+                }
+            };
+            issues.push(issue);
+        }
+        referenced.extend(test_paths);
+
+        for filename in testcase.metadata.auxiliary_filenames() {
+            let path = auxiliary_path(&dir, &filename);
+            if !path.exists() {
+                issues.push(ReferenceIssue::MissingFile {
+                    test: test.clone(),
+                    path: path.clone(),
+                });
+            }
+            referenced.insert(path);
+        }
+    }
+
+    for entry in fs::read_dir(data_dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            find_unreferenced_files(&entry.path(), &referenced, &mut issues)?;
+        }
+    }
+
+    Ok(issues)
+}
+
+fn find_unreferenced_files(
+    dir: &Path,
+    referenced: &BTreeSet<PathBuf>,
+    issues: &mut Vec<ReferenceIssue>,
+) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+
+        if entry.file_type()?.is_dir() {
+            find_unreferenced_files(&path, referenced, issues)?;
+        } else if !referenced.contains(&path) {
+            issues.push(ReferenceIssue::UnreferencedFile { path });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, env, process};
+
+    use super::*;
+    use crate::{ShortintServerKeyTest, TestMetadata, SHORTINT_MODULE_NAME};
+
+    /// Creates a data directory where `files` are stored in the 0.8 shortint module. Returns the
+    /// paths of the data directory and of the module directory.
+    fn test_data_dir(name: &str, files: &[&str]) -> (PathBuf, PathBuf) {
+        let data_dir = env::temp_dir().join(format!(
+            "tfhe-backward-compat-data-validate-{}-{}",
+            name,
+            process::id()
+        ));
+        if data_dir.exists() {
+            fs::remove_dir_all(&data_dir).unwrap();
+        }
+
+        let module_dir = data_dir.join("0_8").join(SHORTINT_MODULE_NAME);
+        fs::create_dir_all(&module_dir).unwrap();
+        for file in files {
+            fs::write(module_dir.join(file), file).unwrap();
+        }
+
+        (data_dir, module_dir)
+    }
+
+    /// A test that references the `client_key` auxiliary file, without extension
+    fn server_key_testcase() -> Testcase {
+        Testcase {
+            tfhe_version_min: "0.8".to_string(),
+            tfhe_module: SHORTINT_MODULE_NAME.to_string(),
+            metadata: TestMetadata::ShortintServerKey(ShortintServerKeyTest {
+                test_filename: Cow::Borrowed("server_key"),
+                client_key_filename: Cow::Borrowed("client_key"),
+                compressed: false,
+            }),
+        }
+    }
+
+    fn check(name: &str, files: &[&str]) -> (PathBuf, Vec<ReferenceIssue>) {
+        let (data_dir, module_dir) = test_data_dir(name, files);
+        let issues = validate_references(&data_dir, &[server_key_testcase()]).unwrap();
+        fs::remove_dir_all(&data_dir).unwrap();
+
+        (module_dir, issues)
+    }
+
+    #[test]
+    fn complete_data_has_no_issue() {
+        let (_, issues) = check(
+            "complete",
+            &["server_key.cbor", "server_key.bcode", "client_key.cbor"],
+        );
+
+        assert_eq!(issues, Vec::new());
+    }
+
+    #[test]
+    fn missing_test_files_are_reported() {
+        let (module_dir, issues) = check("missing", &["client_key.cbor"]);
+
+        assert_eq!(
+            issues,
+            vec![
+                ReferenceIssue::MissingFile {
+                    test: "shortint::server_key".to_string(),
+                    path: module_dir.join("server_key.cbor"),
+                },
+                ReferenceIssue::MissingFile {
+                    test: "shortint::server_key".to_string(),
+                    path: module_dir.join("server_key.bcode"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_format_is_reported() {
+        let (module_dir, issues) = check("format", &["server_key.cbor", "client_key.cbor"]);
+
+        assert_eq!(
+            issues,
+            vec![ReferenceIssue::MissingFormat {
+                test: "shortint::server_key".to_string(),
+                path: module_dir.join("server_key.bcode"),
+            }]
+        );
+    }
+
+    #[test]
+    fn auxiliary_files_without_extension_are_stored_in_cbor() {
+        // Only the cbor file is looked for, so a key stored without extension does not match
+        let (module_dir, issues) = check(
+            "auxiliary",
+            &["server_key.cbor", "server_key.bcode", "client_key"],
+        );
+
+        assert_eq!(
+            issues,
+            vec![
+                ReferenceIssue::MissingFile {
+                    test: "shortint::server_key".to_string(),
+                    path: module_dir.join("client_key.cbor"),
+                },
+                ReferenceIssue::UnreferencedFile {
+                    path: module_dir.join("client_key"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn unreferenced_files_are_reported() {
+        let (module_dir, issues) = check(
+            "unreferenced",
+            &[
+                "server_key.cbor",
+                "server_key.bcode",
+                "client_key.cbor",
+                "ct1.cbor",
+            ],
+        );
+
+        assert_eq!(
+            issues,
+            vec![ReferenceIssue::UnreferencedFile {
+                path: module_dir.join("ct1.cbor"),
+            }]
+        );
+    }
+}